@@ -0,0 +1,171 @@
+//! Async drawing API for [AsyncDisplay].
+
+use embedded_graphics_core::prelude::{Dimensions, PixelColor, Point};
+use embedded_graphics_core::primitives::{PointsIter, Rectangle};
+use embedded_graphics_core::Pixel;
+use embedded_hal::digital::v2::OutputPin;
+
+use crate::models::AsyncModel;
+use crate::{AsyncDisplay, Error};
+use display_interface::AsyncWriteOnlyDataCommand;
+
+/// Async counterpart of the embedded-graphics
+/// [`DrawTarget`](embedded_graphics_core::draw_target::DrawTarget) trait.
+///
+/// All methods `.await` the display interface, which allows other tasks to run while large
+/// transfers are in progress.
+///
+/// The methods draw using [AsyncDisplay::set_pixels], displays with an
+/// [`AsyncFramebufferModel`](crate::models::AsyncFramebufferModel) write into the framebuffer
+/// like their `DrawTarget` implementation. The `_async` suffix keeps the method names apart from
+/// `DrawTarget` when both traits are in scope.
+#[allow(async_fn_in_trait)]
+pub trait AsyncDrawTarget: Dimensions {
+    /// The pixel color type the target accepts.
+    type Color: PixelColor;
+
+    /// Error type to return when a drawing operation fails.
+    type Error;
+
+    /// Draws individual pixels to the display.
+    async fn draw_iter_async<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>;
+
+    /// Fills a given area with an iterator providing a contiguous stream of pixel colors.
+    async fn fill_contiguous_async<I>(
+        &mut self,
+        area: &Rectangle,
+        colors: I,
+    ) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>;
+
+    /// Fills a given area with a solid color.
+    async fn fill_solid_async(
+        &mut self,
+        area: &Rectangle,
+        color: Self::Color,
+    ) -> Result<(), Self::Error>;
+
+    /// Fills the entire display with a solid color.
+    async fn clear_async(&mut self, color: Self::Color) -> Result<(), Self::Error>;
+}
+
+impl<DI, M, RST, TE> AsyncDrawTarget for AsyncDisplay<DI, M, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncModel,
+    RST: OutputPin,
+{
+    type Color = M::ColorFormat;
+    type Error = Error;
+
+    #[cfg(not(feature = "batch"))]
+    async fn draw_iter_async<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounding_box = self.bounding_box();
+
+        // pixels outside of the display are clipped
+        for Pixel(point, color) in pixels {
+            if bounding_box.contains(point) {
+                self.set_pixel(point.x as u16, point.y as u16, color)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "batch")]
+    async fn draw_iter_async<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        use crate::batch::AsyncDrawBatch;
+
+        // pixels outside of the display are clipped
+        let bounding_box = self.bounding_box();
+        let pixels = pixels
+            .into_iter()
+            .filter(|Pixel(point, _)| bounding_box.contains(*point));
+
+        self.draw_batch(pixels).await
+    }
+
+    async fn fill_contiguous_async<I>(
+        &mut self,
+        area: &Rectangle,
+        colors: I,
+    ) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if area.intersection(&self.bounding_box()) != *area {
+            // partially visible areas are clipped pixel by pixel
+            return self
+                .draw_iter_async(
+                    area.points()
+                        .zip(colors)
+                        .map(|(point, color)| Pixel(point, color)),
+                )
+                .await;
+        }
+
+        if let Some(bottom_right) = area.bottom_right() {
+            let mut count = 0u32;
+            let max = area.size.width * area.size.height;
+
+            let mut colors = colors.into_iter().take_while(|_| {
+                count += 1;
+                count <= max
+            });
+
+            let sx = area.top_left.x as u16;
+            let sy = area.top_left.y as u16;
+            let ex = bottom_right.x as u16;
+            let ey = bottom_right.y as u16;
+            self.set_pixels(sx, sy, ex, ey, &mut colors).await
+        } else {
+            // nothing to draw
+            Ok(())
+        }
+    }
+
+    async fn fill_solid_async(
+        &mut self,
+        area: &Rectangle,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        let fb_size = self.options.framebuffer_size();
+        let fb_rect = Rectangle::with_corners(
+            Point::new(0, 0),
+            Point::new(fb_size.0 as i32 - 1, fb_size.1 as i32 - 1),
+        );
+        let area = area.intersection(&fb_rect);
+
+        if let Some(bottom_right) = area.bottom_right() {
+            let pixel_count = (area.size.width * area.size.height) as usize;
+            let colors = core::iter::repeat(color).take(pixel_count);
+
+            let sx = area.top_left.x as u16;
+            let sy = area.top_left.y as u16;
+            let ex = bottom_right.x as u16;
+            let ey = bottom_right.y as u16;
+            self.set_pixels(sx, sy, ex, ey, colors).await
+        } else {
+            // nothing to draw
+            Ok(())
+        }
+    }
+
+    async fn clear_async(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let fb_size = self.options.framebuffer_size();
+        let pixel_count = usize::from(fb_size.0) * usize::from(fb_size.1);
+        let colors = core::iter::repeat(color).take(pixel_count); // blank entire HW RAM contents
+        self.set_pixels(0, 0, fb_size.0 - 1, fb_size.1 - 1, colors)
+            .await
+    }
+}
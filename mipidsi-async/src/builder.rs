@@ -1,65 +1,87 @@
-//! [super::Display] builder module
+//! [super::AsyncDisplay] builder module
 
-use display_interface::WriteOnlyDataCommand;
-use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+use display_interface::AsyncWriteOnlyDataCommand;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::{delay::DelayNs, digital::Wait};
 
 use crate::{
-    dcs::Dcs, error::InitError, models::Model, ColorInversion, ColorOrder, Display, ModelOptions,
-    Orientation, RefreshOrder,
+    dcs::{self, AsyncDcs},
+    error::InitError,
+    models::{AsyncModel, GammaControl},
+    AsyncDisplay, ColorInversion, ColorOrder, ModelOptions, Orientation, RefreshOrder,
+    TearingEffect,
 };
 
-/// Builder for [Display] instances.
-///
-/// Exposes all possible display options.
-///
-///
-/// ## Example
-/// ```rust ignore
-/// let mut display = Builder::ili9342c_rgb565(di)
-///     .with_color_order(ColorOrder::Bgr);
-///     .with_display_size(320, 240);
-///     .init(&mut delay, Some(rst)).unwrap();
-/// ```
-pub struct Builder<DI, MODEL>
+/// Builder for [AsyncDisplay] instances
+pub struct AsyncBuilder<DI, MODEL, TE = ()>
 where
-    DI: WriteOnlyDataCommand,
-    MODEL: Model,
+    DI: AsyncWriteOnlyDataCommand,
+    MODEL: AsyncModel,
 {
     di: DI,
     model: MODEL,
     options: ModelOptions,
+    te: TE,
+    tearing_effect: TearingEffect,
 }
 
-impl<DI, MODEL> Builder<DI, MODEL>
+impl<DI, MODEL> AsyncBuilder<DI, MODEL>
 where
-    DI: WriteOnlyDataCommand,
-    MODEL: Model,
+    DI: AsyncWriteOnlyDataCommand,
+    MODEL: AsyncModel,
 {
     ///
-    /// Constructs a new builder from given [WriteOnlyDataCommand], [Model]
-    /// and [ModelOptions]. For use by [Model] helpers, not public
+    /// Constructs a new builder from given [AsyncWriteOnlyDataCommand], [AsyncModel]
+    /// and [ModelOptions]. For use by [AsyncModel] helpers, not public
     ///
     pub(crate) fn new(di: DI, model: MODEL, options: ModelOptions) -> Self {
-        Self { di, model, options }
+        Self {
+            di,
+            model,
+            options,
+            te: (),
+            tearing_effect: TearingEffect::Off,
+        }
     }
 
     ///
-    /// Constructs a new builder for given [Model] using the model's
+    /// Constructs a new builder for given [AsyncModel] using the model's
     /// `default_options`
     ///
     pub fn with_model(di: DI, model: MODEL) -> Self {
-        Self {
-            di,
-            model,
-            options: MODEL::default_options(),
+        Self::new(di, model, MODEL::default_options())
+    }
+
+    ///
+    /// Sets the tearing effect (TE) input pin.
+    ///
+    /// Enables the vertical blanking output of the controller during init,
+    /// [AsyncDisplay::flush_vsync] waits for the TE pin before sending the framebuffer.
+    ///
+    pub fn with_tearing_effect_pin<TE>(self, te: TE) -> AsyncBuilder<DI, MODEL, TE>
+    where
+        TE: Wait,
+    {
+        AsyncBuilder {
+            di: self.di,
+            model: self.model,
+            options: self.options,
+            te,
+            tearing_effect: TearingEffect::Vertical,
         }
     }
+}
 
+impl<DI, MODEL, TE> AsyncBuilder<DI, MODEL, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    MODEL: AsyncModel,
+{
     ///
     /// Sets the invert color flag
     ///
     pub fn with_invert_colors(mut self, color_inversion: ColorInversion) -> Self {
-        self.options.invert_colors = color_inversion;
+        self.options.set_invert_colors(color_inversion);
         self
     }
 
@@ -67,7 +89,7 @@ where
     /// Sets the [ColorOrder]
     ///
     pub fn with_color_order(mut self, color_order: ColorOrder) -> Self {
-        self.options.color_order = color_order;
+        self.options.set_color_order(color_order);
         self
     }
 
@@ -75,7 +97,7 @@ where
     /// Sets the [Orientation]
     ///
     pub fn with_orientation(mut self, orientation: Orientation) -> Self {
-        self.options.orientation = orientation;
+        self.options.set_orientation(orientation);
         self
     }
 
@@ -83,7 +105,7 @@ where
     /// Sets refresh order
     ///
     pub fn with_refresh_order(mut self, refresh_order: RefreshOrder) -> Self {
-        self.options.refresh_order = refresh_order;
+        self.options.set_refresh_order(refresh_order);
         self
     }
 
@@ -91,7 +113,7 @@ where
     /// Sets the display size
     ///
     pub fn with_display_size(mut self, width: u16, height: u16) -> Self {
-        self.options.display_size = (width, height);
+        self.options.set_display_size((width, height));
         self
     }
 
@@ -99,7 +121,7 @@ where
     /// Sets the framebuffer size
     ///
     pub fn with_framebuffer_size(mut self, width: u16, height: u16) -> Self {
-        self.options.framebuffer_size = (width, height);
+        self.options.set_framebuffer_size((width, height));
         self
     }
 
@@ -110,39 +132,65 @@ where
         mut self,
         window_offset_handler: fn(_: &ModelOptions) -> (u16, u16),
     ) -> Self {
-        self.options.window_offset_handler = window_offset_handler;
+        self.options
+            .set_window_offset_handler(window_offset_handler);
         self
     }
 
     ///
-    /// Consumes the builder to create a new [Display] with an optional reset [OutputPin].
-    /// Blocks using the provided [DelayUs] `delay_source` to perform the display initialization.
-    /// The display will be awake ready to use, no need to call [Display::wake] after init.
+    /// Consumes the builder to create a new [AsyncDisplay] with an optional reset [OutputPin].
+    /// Waits using the provided [DelayNs] `delay_source` to perform the display initialization.
+    /// The display will be awake ready to use, no need to call [AsyncDisplay::wake] after init.
     ///
     /// ### WARNING
     /// The reset pin needs to be in *high* state in order for the display to operate.
     /// If it wasn't provided the user needs to ensure this is the case.
-    pub fn init<RST>(
+    pub async fn init<RST>(
         mut self,
-        delay_source: &mut impl DelayUs<u32>,
+        delay_source: &mut impl DelayNs,
         mut rst: Option<RST>,
-    ) -> Result<Display<DI, MODEL, RST>, InitError<RST::Error>>
+    ) -> Result<AsyncDisplay<DI, MODEL, RST, TE>, InitError<RST::Error>>
     where
         RST: OutputPin,
     {
-        let mut dcs = Dcs::write_only(self.di);
+        let mut dcs = AsyncDcs::write_only(self.di);
         let madctl = self
             .model
-            .init(&mut dcs, delay_source, &self.options, &mut rst)?;
-        let display = Display {
+            .init(&mut dcs, delay_source, &self.options, &mut rst)
+            .await?;
+        if self.tearing_effect != TearingEffect::Off {
+            dcs.write_command(dcs::SetTearingEffect(self.tearing_effect))
+                .await?;
+        }
+        self.model.update_options(&self.options)?;
+        let display = AsyncDisplay {
             dcs,
             model: self.model,
             rst,
+            te: self.te,
             options: self.options,
             madctl,
             sleeping: false, // TODO: init should lock state
+            display_on: true,
+            idle: false,
         };
 
         Ok(display)
     }
 }
+
+impl<DI, MODEL, TE> AsyncBuilder<DI, MODEL, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    MODEL: AsyncModel + GammaControl,
+{
+    ///
+    /// Sets the gamma table which is written to the display during init.
+    ///
+    /// Only available for models which implement [GammaControl].
+    ///
+    pub fn with_gamma(mut self, gamma: MODEL::Gamma) -> Self {
+        self.model.set_gamma(gamma);
+        self
+    }
+}
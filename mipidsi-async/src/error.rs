@@ -1,24 +1,20 @@
-//! [Error] module for [super::Display]
+//! [Error] module for [super::AsyncDisplay]
 
 use display_interface::DisplayError;
 
-/// Error returned by [`Builder::init`](crate::Builder).
+pub use mipidsi::error::{Error, InitError};
+
+/// Error returned by [`AsyncDisplay::flush_vsync`](crate::AsyncDisplay::flush_vsync).
 #[derive(Debug)]
-pub enum InitError<PE> {
+pub enum VsyncError<PE> {
     /// Error caused by the display interface.
     DisplayError,
-    /// Error caused by the reset pin's [`OutputPin`](embedded_hal::digital::v2::OutputPin) implementation.
+    /// Error caused by the tearing effect pin's [`Wait`](embedded_hal_async::digital::Wait) implementation.
     Pin(PE),
 }
 
-///
-/// Alias of [DisplayError] for out-of-init use cases
-/// since the pin error is only possible during [super::Builder] use
-///
-pub type Error = DisplayError;
-
-impl<PE> From<DisplayError> for InitError<PE> {
+impl<PE> From<DisplayError> for VsyncError<PE> {
     fn from(_: DisplayError) -> Self {
-        InitError::DisplayError
+        VsyncError::DisplayError
     }
 }
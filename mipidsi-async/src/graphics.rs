@@ -1,84 +1,153 @@
-use embedded_graphics_core::prelude::{DrawTarget, Point, RgbColor, Size};
-use embedded_graphics_core::primitives::Rectangle;
+use embedded_graphics_core::prelude::{Dimensions, DrawTarget, Size};
+use embedded_graphics_core::primitives::{PointsIter, Rectangle};
 use embedded_graphics_core::{prelude::OriginDimensions, Pixel};
 use embedded_hal::digital::v2::OutputPin;
 
-use crate::dcs::BitsPerPixel;
-use crate::models::Model;
-use crate::{Display, Error};
-use display_interface::WriteOnlyDataCommand;
+use crate::models::{AsyncFramebufferModel, AsyncModel};
+use crate::{AsyncDisplay, Error};
+use display_interface::AsyncWriteOnlyDataCommand;
 
-impl<DI, M, RST> DrawTarget for Display<DI, M, RST>
+impl<DI, M, RST, TE> DrawTarget for AsyncDisplay<DI, M, RST, TE>
 where
-    DI: WriteOnlyDataCommand,
-    M: Model,
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncFramebufferModel,
     RST: OutputPin,
 {
     type Error = Error;
     type Color = M::ColorFormat;
 
-    #[cfg(not(feature = "batch"))]
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
     {
-        for pixel in pixels {
-            let x = pixel.0.x as u16;
-            let y = pixel.0.y as u16;
+        self.framebuffer_target().draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.framebuffer_target().fill_contiguous(area, colors)
+    }
 
-            self.set_pixel(x, y, pixel.1)?;
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.framebuffer_target().clear(color)
+    }
+}
+
+impl<DI, MODEL, RST, TE> OriginDimensions for AsyncDisplay<DI, MODEL, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    MODEL: AsyncModel,
+    RST: OutputPin,
+{
+    fn size(&self) -> Size {
+        let ds = self.options.display_size();
+        let (width, height) = (u32::from(ds.0), u32::from(ds.1));
+        Size::new(width, height)
+    }
+}
+
+/// [DrawTarget] writing into the framebuffer of an [AsyncFramebufferModel].
+///
+/// Passed to the render callback of [AsyncDisplay::swap_and_flush] to draw into the back buffer.
+pub struct FramebufferTarget<'a, M>
+where
+    M: AsyncFramebufferModel,
+{
+    model: &'a mut M,
+    // Display size (w, h) in the current orientation
+    size: (u16, u16),
+}
+
+impl<'a, M> FramebufferTarget<'a, M>
+where
+    M: AsyncFramebufferModel,
+{
+    pub(crate) fn new(model: &'a mut M, width: u16, height: u16) -> Self {
+        Self {
+            model,
+            size: (width, height),
+        }
+    }
+
+    // Writes pixel colors in a rectangular region of the framebuffer.
+    fn write_pixels<T>(
+        &mut self,
+        sx: u16,
+        sy: u16,
+        ex: u16,
+        ey: u16,
+        colors: T,
+    ) -> Result<(), Error>
+    where
+        T: IntoIterator<Item = M::ColorFormat>,
+    {
+        let mut x = sx;
+        let mut y = sy;
+        for color in colors {
+            self.model.write_pixel(x, y, color)?;
+
+            if x == ex {
+                if y == ey {
+                    // this was the last line, finish
+                    break;
+                }
+                // end of line, go to next line
+                y += 1;
+                x = sx;
+            } else {
+                // go to next pixel in current line
+                x += 1;
+            }
         }
 
         Ok(())
     }
+}
+
+impl<M> DrawTarget for FramebufferTarget<'_, M>
+where
+    M: AsyncFramebufferModel,
+{
+    type Error = Error;
+    type Color = M::ColorFormat;
 
-    #[cfg(feature = "batch")]
-    fn draw_iter<T>(&mut self, item: T) -> Result<(), Self::Error>
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
-        T: IntoIterator<Item = Pixel<Self::Color>>,
+        I: IntoIterator<Item = Pixel<Self::Color>>,
     {
-        use crate::batch::DrawBatch;
+        let bounding_box = self.bounding_box();
+
+        // pixels outside of the display are clipped
+        for Pixel(point, color) in pixels {
+            if bounding_box.contains(point) {
+                self.model
+                    .write_pixel(point.x as u16, point.y as u16, color)?;
+            }
+        }
 
-        self.draw_batch(item)
+        Ok(())
     }
 
     fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Self::Color>,
     {
-        if let Some(bottom_right) = area.bottom_right() {
-            let mut count = 0u32;
-            let max = area.size.width * area.size.height;
-
-            let mut colors = colors.into_iter().take_while(|_| {
-                count += 1;
-                count <= max
-            });
-
-            let sx = area.top_left.x as u16;
-            let sy = area.top_left.y as u16;
-            let ex = bottom_right.x as u16;
-            let ey = bottom_right.y as u16;
-            self.set_pixels(sx, sy, ex, ey, &mut colors)
-        } else {
-            // nothing to draw
-            Ok(())
+        if area.intersection(&self.bounding_box()) != *area {
+            // partially visible areas are clipped pixel by pixel
+            return self.draw_iter(
+                area.points()
+                    .zip(colors)
+                    .map(|(point, color)| Pixel(point, color)),
+            );
         }
-    }
-
-    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
-        let fb_size = self.options.framebuffer_size();
-        let fb_rect = Rectangle::with_corners(
-            Point::new(0, 0),
-            Point::new(fb_size.0 as i32 - 1, fb_size.1 as i32 - 1),
-        );
-        let area = area.intersection(&fb_rect);
 
         if let Some(bottom_right) = area.bottom_right() {
             let mut count = 0u32;
             let max = area.size.width * area.size.height;
 
-            let mut colors = core::iter::repeat(color).take_while(|_| {
+            let mut colors = colors.into_iter().take_while(|_| {
                 count += 1;
                 count <= max
             });
@@ -87,7 +156,7 @@ where
             let sy = area.top_left.y as u16;
             let ex = bottom_right.x as u16;
             let ey = bottom_right.y as u16;
-            self.set_pixels(sx, sy, ex, ey, &mut colors)
+            self.write_pixels(sx, sy, ex, ey, &mut colors)
         } else {
             // nothing to draw
             Ok(())
@@ -95,67 +164,15 @@ where
     }
 
     fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
-        let fb_size = self.options.framebuffer_size();
-        let pixel_count = usize::from(fb_size.0) * usize::from(fb_size.1);
-        let colors = core::iter::repeat(color).take(pixel_count); // blank entire HW RAM contents
-        self.set_pixels(0, 0, fb_size.0 - 1, fb_size.1 - 1, colors)
+        self.model.clear(color)
     }
 }
 
-impl<DI, MODEL, RST> OriginDimensions for Display<DI, MODEL, RST>
+impl<M> OriginDimensions for FramebufferTarget<'_, M>
 where
-    DI: WriteOnlyDataCommand,
-    MODEL: Model,
-    RST: OutputPin,
+    M: AsyncFramebufferModel,
 {
     fn size(&self) -> Size {
-        let ds = self.options.display_size();
-        let (width, height) = (u32::from(ds.0), u32::from(ds.1));
-        Size::new(width, height)
-    }
-}
-
-impl BitsPerPixel {
-    /// Returns the bits per pixel for a embedded-graphics [`RgbColor`].
-    pub const fn from_rgb_color<C: RgbColor>() -> Self {
-        let bpp = C::MAX_R.trailing_ones() + C::MAX_G.trailing_ones() + C::MAX_B.trailing_ones();
-
-        match bpp {
-            3 => Self::Three,
-            8 => Self::Eight,
-            12 => Self::Twelve,
-            16 => Self::Sixteen,
-            18 => Self::Eighteen,
-            24 => Self::TwentyFour,
-            _ => panic!("invalid RgbColor bits per pixel"),
-        }
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use crate::dcs::BitsPerPixel;
-    use embedded_graphics_core::pixelcolor::*;
-
-    #[test]
-    fn bpp_from_rgb_color_works() {
-        assert_eq!(
-            BitsPerPixel::from_rgb_color::<Rgb565>(),
-            BitsPerPixel::Sixteen
-        );
-        assert_eq!(
-            BitsPerPixel::from_rgb_color::<Rgb666>(),
-            BitsPerPixel::Eighteen
-        );
-        assert_eq!(
-            BitsPerPixel::from_rgb_color::<Rgb888>(),
-            BitsPerPixel::TwentyFour
-        );
-    }
-
-    #[test]
-    #[should_panic]
-    fn bpp_from_rgb_color_invalid_panics() {
-        BitsPerPixel::from_rgb_color::<Rgb555>();
+        Size::new(u32::from(self.size.0), u32::from(self.size.1))
     }
 }
@@ -1 +1,893 @@
-// TODO
+#![no_std]
+// associated re-typing not supported in rust yet
+#![allow(clippy::type_complexity)]
+#![warn(missing_docs)]
+
+//! This crate provides an async generic display driver to connect to TFT displays
+//! that implement the [MIPI Display Command Set](https://www.mipi.org/specifications/display-command-set).
+//!
+//! It is the async counterpart of [mipidsi](https://crates.io/crates/mipidsi) and shares the DCS command
+//! encoding, [ModelOptions] and the supported models with it.
+//!
+//! Uses [display_interface](https://crates.io/crates/display-interface) to talk to the hardware via transports.
+//!
+//! An optional batching of draws is supported via the `batch` feature (default on)
+//!
+//! ### List of supported models
+//!
+//! * ST7789
+//! * ST7735
+//! * ILI9486
+//! * ILI9488
+//! * ILI9341
+//! * ILI9342C
+//! * GC9A01
+//! * ST7796S
+//! * HX8357D
+//!
+//! ## Example
+//! **For the ST7789 display with a framebuffer on the MCU, using the SPI interface:**
+//! ```rust ignore
+//! use mipidsi_async::AsyncBuilder;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let mut framebuffer = [0u16; 240 * 320];
+//! let mut display = AsyncBuilder::st7789_framebuffer(di, &mut framebuffer)
+//!     .init(&mut delay, Some(rst))
+//!     .await
+//!     .unwrap();
+//!
+//! // Draw into the framebuffer and send the modified areas to the display
+//! display.clear(Rgb565::BLACK).unwrap();
+//! display.flush().await.unwrap();
+//! ```
+//!
+//! ## Troubleshooting
+//! See [document](https://github.com/almindor/mipidsi/blob/master/docs/TROUBLESHOOTING.md)
+
+use core::fmt::Debug;
+use core::future::{poll_fn, Future};
+use core::ops::Range;
+use core::pin::pin;
+
+use dcs::AsyncDcs;
+use display_interface::AsyncWriteOnlyDataCommand;
+use embedded_graphics_core::{
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::{delay::DelayNs, digital::Wait};
+
+pub mod error;
+pub use error::Error;
+
+pub use mipidsi::options;
+pub use options::*;
+
+mod builder;
+pub use builder::AsyncBuilder;
+
+pub mod dcs;
+
+pub mod models;
+use models::{AsyncFramebufferModel, AsyncModel, BrightnessControl, DoubleBuffer};
+
+mod graphics;
+pub use graphics::FramebufferTarget;
+
+mod async_graphics;
+pub use async_graphics::AsyncDrawTarget;
+
+pub use mipidsi::{idle_mode_color, Band, TestImage};
+
+#[cfg(feature = "batch")]
+mod batch;
+
+#[cfg(test)]
+mod mock;
+
+///
+/// Display driver to connect to TFT displays.
+///
+pub struct AsyncDisplay<DI, MODEL, RST, TE = ()>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    MODEL: AsyncModel,
+    RST: OutputPin,
+{
+    // DCS provider
+    dcs: AsyncDcs<DI>,
+    // Model
+    model: MODEL,
+    // Reset pin
+    rst: Option<RST>,
+    // Tearing effect pin, `()` if not connected
+    te: TE,
+    // Model Options, includes current orientation
+    options: ModelOptions,
+    // Current MADCTL value copy for runtime updates
+    madctl: dcs::SetAddressMode,
+    // State monitor for sleeping TODO: refactor to a Model-connected state machine
+    sleeping: bool,
+    // State monitor for the display output
+    display_on: bool,
+    // State monitor for idle mode
+    idle: bool,
+}
+
+impl<DI, M, RST, TE> AsyncDisplay<DI, M, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncModel,
+    RST: OutputPin,
+{
+    ///
+    /// Returns currently set [Orientation]
+    ///
+    pub fn orientation(&self) -> Orientation {
+        self.options.orientation()
+    }
+
+    ///
+    /// Sets display [Orientation] with mirror image parameter
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// display.orientation(Orientation::Portrait(false)).unwrap();
+    /// ```
+    pub async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), Error> {
+        self.madctl = self.madctl.with_orientation(orientation); // set orientation
+        self.dcs.write_command(self.madctl).await?;
+        self.options.set_orientation(orientation);
+        self.model.update_options(&self.options)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Sets a pixel color at the given coords.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x coordinate
+    /// * `y` - y coordinate
+    /// * `color` - the color value in pixel format of the display [AsyncModel]
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// display.set_pixel(100, 200, Rgb666::new(251, 188, 20)).await.unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::OutOfBoundsError] if the pixel is outside of the display.
+    pub async fn set_pixel(&mut self, x: u16, y: u16, color: M::ColorFormat) -> Result<(), Error> {
+        let (width, height) = self.options.display_size();
+        if x >= width || y >= height {
+            return Err(Error::OutOfBoundsError);
+        }
+
+        self.set_pixels(x, y, x, y, core::iter::once(color)).await
+    }
+
+    ///
+    /// Sets pixel colors in a rectangular region.
+    ///
+    /// The color values from the `colors` iterator will be drawn to the given region starting
+    /// at the top left corner and continuing, row first, to the bottom right corner. No bounds
+    /// checking is performed on the `colors` iterator and drawing will wrap around if the
+    /// iterator returns more color values than the number of pixels in the given region.
+    ///
+    /// Models with a framebuffer on the MCU write the pixels into the framebuffer, they are sent
+    /// to the display by the next [flush](Self::flush). All other models stream the pixels
+    /// directly to the display controller.
+    ///
+    /// # Arguments
+    ///
+    /// * `sx` - x coordinate start
+    /// * `sy` - y coordinate start
+    /// * `ex` - x coordinate end
+    /// * `ey` - y coordinate end
+    /// * `colors` - anything that can provide `IntoIterator<Item = u16>` to iterate over pixel data
+    pub async fn set_pixels<T>(
+        &mut self,
+        sx: u16,
+        sy: u16,
+        ex: u16,
+        ey: u16,
+        colors: T,
+    ) -> Result<(), Error>
+    where
+        T: IntoIterator<Item = M::ColorFormat>,
+    {
+        let area = Rectangle::with_corners(
+            Point::new(i32::from(sx), i32::from(sy)),
+            Point::new(i32::from(ex), i32::from(ey)),
+        );
+        let mut colors = colors.into_iter();
+        if self.model.write_framebuffer(&area, &mut colors)? {
+            return Ok(());
+        }
+
+        self.set_address_window(sx, sy, ex, ey).await?;
+        self.model.write_pixels(&mut self.dcs, colors).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Renders the display in horizontal bands using a small `buffer` instead of a full framebuffer.
+    ///
+    /// The display is split into bands of `buffer.len() / width` rows. `render` is called once per
+    /// band to draw the whole scene into a [Band], which is sent to the display afterwards.
+    /// Returns [Error::OutOfBoundsError] if the buffer can't hold a single row.
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// let mut buffer = [Rgb565::BLACK; 240 * 16];
+    /// display.render_bands(&mut buffer, |band| {
+    ///     band.clear(Rgb565::BLACK).unwrap();
+    ///     Circle::new(Point::new(120, 60), 40).into_styled(style).draw(band).unwrap();
+    /// }).await.unwrap();
+    /// ```
+    pub async fn render_bands<F>(
+        &mut self,
+        buffer: &mut [M::ColorFormat],
+        mut render: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(&mut Band<'_, M::ColorFormat>),
+    {
+        let (width, height) = self.options.display_size();
+        let mut bands = mipidsi::__private::Bands::new(buffer.len(), width, height)?;
+
+        while let Some((top, bottom, pixels)) = bands.render_next(buffer, &mut render) {
+            self.set_pixels(0, top, width - 1, bottom, pixels.iter().copied())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Sets scroll region
+    /// # Arguments
+    ///
+    /// * `tfa` - Top fixed area
+    /// * `vsa` - Vertical scrolling area
+    /// * `bfa` - Bottom fixed area
+    ///
+    pub async fn set_scroll_region(&mut self, tfa: u16, vsa: u16, bfa: u16) -> Result<(), Error> {
+        let vscrdef = dcs::SetScrollArea::new(tfa, vsa, bfa);
+        self.dcs.write_command(vscrdef).await
+    }
+
+    ///
+    /// Sets scroll offset "shifting" the displayed picture
+    /// # Arguments
+    ///
+    /// * `offset` - scroll offset in pixels
+    ///
+    pub async fn set_scroll_offset(&mut self, offset: u16) -> Result<(), Error> {
+        let vscad = dcs::SetScrollStart::new(offset);
+        self.dcs.write_command(vscad).await
+    }
+
+    ///
+    /// Selects one of the predefined gamma curves of the display controller.
+    ///
+    pub async fn set_gamma_curve(&mut self, gamma_curve: dcs::GammaCurve) -> Result<(), Error> {
+        self.dcs
+            .write_command(dcs::SetGammaCurve(gamma_curve))
+            .await
+    }
+
+    ///
+    /// Sets the rows of the partial display area.
+    ///
+    /// Only the rows in the partial area are displayed after [Self::enter_partial_mode] is called,
+    /// the rest of the panel shows the background color and consumes less power. The rows are
+    /// given in the framebuffer coordinates of the controller, they aren't affected by the
+    /// display orientation.
+    ///
+    /// Returns [Error::OutOfBoundsError] if `rows` is empty or ends after the last row of the
+    /// framebuffer.
+    ///
+    pub async fn set_partial_area(&mut self, rows: Range<u16>) -> Result<(), Error> {
+        if rows.is_empty() || rows.end > self.options.framebuffer_size().1 {
+            return Err(Error::OutOfBoundsError);
+        }
+
+        self.dcs
+            .write_command(dcs::SetPartialRows::new(rows.start, rows.end - 1))
+            .await
+    }
+
+    ///
+    /// Enters partial mode, only the area set by [Self::set_partial_area] is displayed.
+    ///
+    pub async fn enter_partial_mode(&mut self) -> Result<(), Error> {
+        self.dcs.write_command(dcs::EnterPartialMode).await
+    }
+
+    ///
+    /// Leaves partial mode and displays the whole framebuffer again.
+    ///
+    pub async fn enter_normal_mode(&mut self) -> Result<(), Error> {
+        self.dcs.write_command(dcs::EnterNormalMode).await
+    }
+
+    ///
+    /// Release resources allocated to this driver back.
+    /// This returns the display interface, reset pin and and the model deconstructing the driver.
+    ///
+    /// The tearing effect pin is dropped, use [Self::release_with_te] to get it back.
+    ///
+    pub fn release(self) -> (DI, M, Option<RST>) {
+        (self.dcs.release(), self.model, self.rst)
+    }
+
+    // Sets the address window for the display.
+    async fn set_address_window(
+        &mut self,
+        sx: u16,
+        sy: u16,
+        ex: u16,
+        ey: u16,
+    ) -> Result<(), Error> {
+        set_address_window_async(&mut self.dcs, &mut self.options, sx, sy, ex, ey).await
+    }
+
+    ///
+    /// Configures the tearing effect output.
+    ///
+    pub async fn set_tearing_effect(&mut self, tearing_effect: TearingEffect) -> Result<(), Error> {
+        self.dcs
+            .write_command(dcs::SetTearingEffect(tearing_effect))
+            .await
+    }
+
+    ///
+    /// Returns `true` if the display output is currently turned on.
+    ///
+    pub fn is_display_on(&self) -> bool {
+        self.display_on
+    }
+
+    ///
+    /// Turns the display output on or off.
+    ///
+    /// Unlike [Self::sleep] and [Self::wake] this takes effect immediately. The display
+    /// memory is kept while the output is turned off and can still be written, which makes it
+    /// possible to blank the panel while the whole frame is redrawn.
+    ///
+    pub async fn set_display_on(&mut self, on: bool) -> Result<(), Error> {
+        if on {
+            self.dcs.write_command(dcs::SetDisplayOn).await?;
+        } else {
+            self.dcs.write_command(dcs::SetDisplayOff).await?;
+        }
+        self.display_on = on;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns `true` if the display is currently in idle mode.
+    ///
+    pub fn is_idle_mode(&self) -> bool {
+        self.idle
+    }
+
+    ///
+    /// Enables or disables idle mode.
+    ///
+    /// In idle mode the display only shows 8 colors, which reduces power consumption. Drawing
+    /// is still possible in idle mode, but the controller reduces each color channel to its most
+    /// significant bit. Use [idle_mode_color] to get the color which is shown in idle mode.
+    ///
+    pub async fn set_idle_mode(&mut self, idle: bool) -> Result<(), Error> {
+        if idle {
+            self.dcs.write_command(dcs::EnterIdleMode).await?;
+        } else {
+            self.dcs.write_command(dcs::ExitIdleMode).await?;
+        }
+        self.idle = idle;
+
+        Ok(())
+    }
+
+    ///
+    /// Sets the scanline at which the tearing effect output is triggered.
+    ///
+    /// The tearing effect output needs to be enabled using [Self::set_tearing_effect] with
+    /// [TearingEffect::Vertical]. The output is then triggered when the display refresh reaches
+    /// the given scanline instead of the start of the vertical blanking period.
+    ///
+    pub async fn set_tear_scanline(&mut self, scanline: u16) -> Result<(), Error> {
+        self.dcs.write_command(dcs::SetTearScanline(scanline)).await
+    }
+
+    ///
+    /// Returns `true` if display is currently set to sleep.
+    ///
+    pub fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+
+    ///
+    /// Puts the display to sleep, reducing power consumption.
+    /// Need to call [Self::wake] before issuing other commands
+    ///
+    pub async fn sleep<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error> {
+        self.dcs.write_command(dcs::EnterSleepMode).await?;
+        delay.delay_us(M::SLEEP_DELAY_US).await;
+        self.sleeping = true;
+        Ok(())
+    }
+
+    ///
+    /// Wakes the display after it's been set to sleep via [Self::sleep]
+    ///
+    pub async fn wake<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error> {
+        self.dcs.write_command(dcs::ExitSleepMode).await?;
+        delay.delay_us(M::SLEEP_DELAY_US).await;
+        self.sleeping = false;
+        Ok(())
+    }
+
+    /// Returns the DCS interface for sending raw commands.
+    ///
+    /// # Safety
+    ///
+    /// Sending raw commands to the controller can lead to undefined behaviour,
+    /// because the rest of the code isn't aware of any state changes that were caused by sending raw commands.
+    /// The user must ensure that the state of the controller isn't altered in a way that interferes with the normal
+    /// operation of this crate.
+    pub unsafe fn dcs(&mut self) -> &mut AsyncDcs<DI> {
+        &mut self.dcs
+    }
+}
+
+impl<DI, M, RST, TE> AsyncDisplay<DI, M, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncFramebufferModel,
+    RST: OutputPin,
+{
+    ///
+    /// Sends the areas of the model's framebuffer which were modified since the last flush
+    /// to the display.
+    ///
+    /// Each modified area is sent using its own address window, unchanged parts of the
+    /// framebuffer are not transferred.
+    ///
+    pub async fn flush(&mut self) -> Result<(), Error> {
+        flush_dirty_areas(&mut self.dcs, &mut self.options, &mut self.model).await
+    }
+
+    ///
+    /// Sends the given `area` of the model's framebuffer to the display.
+    ///
+    /// The area is clipped to the display size in the current orientation,
+    /// any window offset of the display variant is applied.
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// display.flush_region(Rectangle::new(Point::new(10, 10), Size::new(16, 16))).await.unwrap();
+    /// ```
+    pub async fn flush_region(&mut self, area: Rectangle) -> Result<(), Error> {
+        flush_area(&mut self.dcs, &mut self.options, &mut self.model, area).await
+    }
+
+    // Returns a draw target writing into the model's framebuffer.
+    fn framebuffer_target(&mut self) -> FramebufferTarget<'_, M> {
+        let (width, height) = self.options.display_size();
+        FramebufferTarget::new(&mut self.model, width, height)
+    }
+}
+
+impl<DI, M, RST, TE> AsyncDisplay<DI, M, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncFramebufferModel,
+    RST: OutputPin,
+    TE: Wait,
+{
+    ///
+    /// Waits for the start of the vertical blanking period on the tearing effect pin
+    /// and sends the modified areas of the framebuffer to the display, see [Self::flush].
+    ///
+    /// Starting the transfer right after the TE edge prevents the controller from
+    /// showing a partially updated frame.
+    ///
+    /// Only the first modified area is sent right after the TE edge, the following areas are
+    /// sent afterwards without waiting for the pin again. If several areas are modified, the
+    /// later ones can still show tearing if the transfer takes longer than the blanking period.
+    /// Use [Self::flush_region] with a single area covering all changes to avoid this.
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// let mut display = AsyncBuilder::st7789_framebuffer(di, &mut buffer)
+    ///     .with_tearing_effect_pin(te)
+    ///     .init(&mut delay, Some(rst))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// display.flush_vsync().await.unwrap();
+    /// ```
+    pub async fn flush_vsync(&mut self) -> Result<(), error::VsyncError<TE::Error>> {
+        self.te
+            .wait_for_rising_edge()
+            .await
+            .map_err(error::VsyncError::Pin)?;
+        self.flush().await?;
+        Ok(())
+    }
+}
+
+impl<DI, M, RST, TE> AsyncDisplay<DI, M, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncModel,
+    RST: OutputPin,
+    TE: Wait,
+{
+    ///
+    /// Release resources allocated to this driver back.
+    /// This returns the display interface, model, reset pin and the tearing effect pin
+    /// deconstructing the driver.
+    ///
+    pub fn release_with_te(self) -> (DI, M, Option<RST>, TE) {
+        (self.dcs.release(), self.model, self.rst, self.te)
+    }
+}
+
+impl<DI, M, RST, TE> AsyncDisplay<DI, M, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncModel + BrightnessControl,
+    RST: OutputPin,
+{
+    ///
+    /// Sets the display brightness.
+    ///
+    /// Enables the brightness control of the controller and sets the brightness value of the
+    /// backlight PWM output, `0` is the lowest and `255` the highest brightness.
+    /// Only available for models which implement [BrightnessControl].
+    ///
+    pub async fn set_brightness(&mut self, brightness: u8) -> Result<(), Error> {
+        self.dcs
+            .write_command(dcs::WriteCtrlDisplay::new(true, false, true))
+            .await?;
+        self.dcs
+            .write_command(dcs::WriteDisplayBrightness(brightness))
+            .await
+    }
+
+    ///
+    /// Sets the content adaptive brightness control (CABC) mode.
+    ///
+    /// Only available for models which implement [BrightnessControl].
+    ///
+    pub async fn set_cabc_mode(&mut self, mode: CabcMode) -> Result<(), Error> {
+        self.dcs.write_command(dcs::WriteCabc(mode)).await
+    }
+}
+
+impl<DI, M, RST, TE> AsyncDisplay<DI, DoubleBuffer<M>, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncFramebufferModel,
+    RST: OutputPin,
+{
+    ///
+    /// Exchanges the front and back buffer of the [DoubleBuffer] model.
+    ///
+    /// Call [Self::flush] afterwards to send the new front buffer to the display.
+    ///
+    pub fn swap(&mut self) {
+        self.model.swap();
+    }
+
+    ///
+    /// Swaps the buffers and sends the new front buffer to the display.
+    ///
+    /// `render` is called with the back buffer once the first transfer of the flush is in flight,
+    /// i.e. after the first poll of the flush returned. Only the transfer which is pending at that
+    /// point overlaps with `render`, the flush continues after `render` returns.
+    ///
+    /// The framebuffer models send the pixel data from an iterator, which the display interface
+    /// copies with the CPU. The pixel data is therefore always sent after `render` returned,
+    /// rendering only overlaps with the address window commands of the first area.
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// loop {
+    ///     display
+    ///         .swap_and_flush(|back| {
+    ///             Circle::new(position, 10).into_styled(style).draw(back).unwrap();
+    ///         })
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn swap_and_flush<F>(&mut self, render: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut FramebufferTarget<'_, M>),
+    {
+        self.model.swap();
+
+        let (width, height) = self.options.display_size();
+        let (front, back) = self.model.split_mut();
+        let mut back = FramebufferTarget::new(back, width, height);
+        let mut render = Some(render);
+
+        let mut flush = pin!(flush_dirty_areas(&mut self.dcs, &mut self.options, front));
+        poll_fn(|cx| {
+            let result = flush.as_mut().poll(cx);
+            if let Some(render) = render.take() {
+                render(&mut back);
+            }
+            result
+        })
+        .await
+    }
+}
+
+impl<DI, MODEL, RST, TE> Debug for AsyncDisplay<DI, MODEL, RST, TE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    MODEL: AsyncModel,
+    RST: OutputPin,
+{
+    fn fmt(&self, _f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+// Sets the address window for the display, applying the window offset of the options.
+async fn set_address_window_async<DI>(
+    dcs: &mut AsyncDcs<DI>,
+    options: &mut ModelOptions,
+    sx: u16,
+    sy: u16,
+    ex: u16,
+    ey: u16,
+) -> Result<(), Error>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    // add clipping offsets if present
+    let offset = options.window_offset();
+    let (sx, sy, ex, ey) = (sx + offset.0, sy + offset.1, ex + offset.0, ey + offset.1);
+
+    dcs.write_command(dcs::SetColumnAddress::new(sx, ex))
+        .await?;
+    dcs.write_command(dcs::SetPageAddress::new(sy, ey)).await
+}
+
+// Sends all areas of a framebuffer model which were modified since the last flush.
+async fn flush_dirty_areas<DI, M>(
+    dcs: &mut AsyncDcs<DI>,
+    options: &mut ModelOptions,
+    model: &mut M,
+) -> Result<(), Error>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncFramebufferModel,
+{
+    while let Some(area) = model.dirty_area() {
+        // only mark the area as clean once it was sent, so a failed transfer is retried
+        flush_area(dcs, options, model, area).await?;
+        model.clear_dirty_area(&area);
+    }
+
+    Ok(())
+}
+
+// Sends the given area of a framebuffer model, clipped to the display size.
+async fn flush_area<DI, M>(
+    dcs: &mut AsyncDcs<DI>,
+    options: &mut ModelOptions,
+    model: &mut M,
+    area: Rectangle,
+) -> Result<(), Error>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    M: AsyncFramebufferModel,
+{
+    let (width, height) = options.display_size();
+    let display_area = Rectangle::new(
+        Point::zero(),
+        Size::new(u32::from(width), u32::from(height)),
+    );
+    let area = area.intersection(&display_area);
+
+    if let Some(bottom_right) = area.bottom_right() {
+        let sx = area.top_left.x as u16;
+        let sy = area.top_left.y as u16;
+        let ex = bottom_right.x as u16;
+        let ey = bottom_right.y as u16;
+        set_address_window_async(dcs, options, sx, sy, ex, ey).await?;
+        model.flush(dcs, &area).await
+    } else {
+        // nothing to flush
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics_core::{pixelcolor::Rgb565, prelude::RgbColor};
+
+    use super::*;
+    use crate::{
+        mock::{block_on, MockDelay, MockDisplayInterface, MockOutputPin},
+        models::ST7789Framebuffer,
+    };
+
+    #[test]
+    fn failed_flush_keeps_area_dirty() {
+        let mut buffer = [0u16; 4 * 4];
+        let mut display = block_on(
+            AsyncBuilder::with_model(
+                MockDisplayInterface::new(),
+                ST7789Framebuffer::new(&mut buffer),
+            )
+            .with_display_size(4, 4)
+            .with_framebuffer_size(4, 4)
+            .init(&mut MockDelay, None::<MockOutputPin>),
+        )
+        .unwrap();
+        block_on(display.flush()).unwrap();
+
+        display.model.write_pixel(1, 2, Rgb565::RED).unwrap();
+        display.dcs.di.set_failing(true);
+        assert!(block_on(display.flush()).is_err());
+
+        let area = Rectangle::new(Point::new(1, 2), Size::new(1, 1));
+        assert_eq!(display.model.dirty_area(), Some(area));
+
+        display.dcs.di.set_failing(false);
+        block_on(display.flush()).unwrap();
+        assert_eq!(display.model.dirty_area(), None);
+    }
+
+    #[test]
+    fn st7789_framebuffer_with_240x135_buffer() {
+        let mut buffer = [0u16; 240 * 135];
+        let init = block_on(
+            AsyncBuilder::st7789_framebuffer(MockDisplayInterface::new(), &mut buffer)
+                .init(&mut MockDelay, None::<MockOutputPin>),
+        );
+        assert!(matches!(init, Err(error::InitError::DisplayError)));
+
+        let mut buffer = [0u16; 240 * 135];
+        let display = block_on(
+            AsyncBuilder::st7789_framebuffer(MockDisplayInterface::new(), &mut buffer)
+                .with_display_size(240, 135)
+                .init(&mut MockDelay, None::<MockOutputPin>),
+        )
+        .unwrap();
+        assert_eq!(display.options.display_size(), (240, 135));
+    }
+
+    #[test]
+    fn set_pixels_writes_into_framebuffer() {
+        let mut buffer = [0u16; 4 * 4];
+        let mut display = block_on(
+            AsyncBuilder::st7789_framebuffer(MockDisplayInterface::new(), &mut buffer)
+                .with_display_size(4, 4)
+                .init(&mut MockDelay, None::<MockOutputPin>),
+        )
+        .unwrap();
+        block_on(display.flush()).unwrap();
+        display.dcs.di.clear();
+
+        block_on(display.set_pixels(1, 2, 2, 2, [Rgb565::RED, Rgb565::BLUE])).unwrap();
+        assert_eq!(display.dcs.di.log(), &[]);
+
+        // the next flush sends the pixels instead of overwriting them
+        block_on(display.flush()).unwrap();
+        let log = display.dcs.di.log();
+        assert_eq!(&log[log.len() - 5..], &[0x2C, 0xF8, 0x00, 0x00, 0x1F]);
+    }
+
+    #[test]
+    fn set_pixel_streams_without_framebuffer() {
+        let mut display = block_on(
+            AsyncBuilder::st7789(MockDisplayInterface::new())
+                .init(&mut MockDelay, None::<MockOutputPin>),
+        )
+        .unwrap();
+        display.dcs.di.clear();
+
+        block_on(display.set_pixel(1, 2, Rgb565::RED)).unwrap();
+        assert_eq!(
+            display.dcs.di.log(),
+            &[0x2A, 0, 1, 0, 1, 0x2B, 0, 2, 0, 2, 0x2C, 0xF8, 0x00]
+        );
+    }
+
+    #[test]
+    fn draw_targets_do_not_collide() {
+        use embedded_graphics_core::draw_target::DrawTarget;
+
+        let mut buffer = [0u16; 4 * 4];
+        let mut display = block_on(
+            AsyncBuilder::st7789_framebuffer(MockDisplayInterface::new(), &mut buffer)
+                .with_display_size(4, 4)
+                .init(&mut MockDelay, None::<MockOutputPin>),
+        )
+        .unwrap();
+        block_on(display.flush()).unwrap();
+        display.dcs.di.clear();
+
+        // both draw into the framebuffer
+        display.clear(Rgb565::RED).unwrap();
+        block_on(display.clear_async(Rgb565::BLUE)).unwrap();
+        assert_eq!(display.dcs.di.log(), &[]);
+
+        block_on(display.flush()).unwrap();
+        let log = display.dcs.di.log();
+        assert_eq!(&log[log.len() - 2..], &[0x00, 0x1F]);
+    }
+
+    #[test]
+    fn swap_and_flush_renders_while_first_transfer_is_pending() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use embedded_graphics_core::{draw_target::DrawTarget, prelude::Point, Pixel};
+
+        static TRANSFERS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut front = [0u16; 4 * 4];
+        let mut back = [0u16; 4 * 4];
+        let mut display = block_on(
+            AsyncBuilder::st7789_double_framebuffer(
+                MockDisplayInterface::with_pending_transfers(&TRANSFERS),
+                &mut front,
+                &mut back,
+            )
+            .with_display_size(4, 4)
+            .init(&mut MockDelay, None::<MockOutputPin>),
+        )
+        .unwrap();
+        block_on(display.flush()).unwrap();
+        display
+            .draw_iter([Pixel(Point::new(1, 1), Rgb565::RED)])
+            .unwrap();
+
+        let started = TRANSFERS.load(Ordering::SeqCst);
+        let mut in_flight = 0;
+        block_on(display.swap_and_flush(|back| {
+            in_flight = TRANSFERS.load(Ordering::SeqCst) - started;
+            back.draw_iter([Pixel(Point::new(2, 2), Rgb565::BLUE)])
+                .unwrap();
+        }))
+        .unwrap();
+
+        // the CASET command was in flight while rendering, its parameters, RASET, RAMWR and the
+        // pixel data were only sent afterwards
+        assert_eq!(in_flight, 1);
+        assert_eq!(TRANSFERS.load(Ordering::SeqCst) - started, 6);
+    }
+
+    #[test]
+    fn partial_area_must_fit_into_framebuffer() {
+        let mut display = block_on(
+            AsyncBuilder::st7789(MockDisplayInterface::new())
+                .init(&mut MockDelay, None::<MockOutputPin>),
+        )
+        .unwrap();
+        display.dcs.di.clear();
+
+        assert!(block_on(display.set_partial_area(20..20)).is_err());
+        assert!(block_on(display.set_partial_area(300..321)).is_err());
+        assert_eq!(display.dcs.di.log(), &[]);
+
+        block_on(display.set_partial_area(300..320)).unwrap();
+        assert_eq!(display.dcs.di.log(), &[0x30, 0x01, 0x2C, 0x01, 0x3F]);
+    }
+}
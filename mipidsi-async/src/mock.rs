@@ -0,0 +1,182 @@
+//! Mock display interface, pin and delay for unit tests.
+
+use core::convert::Infallible;
+use core::future::Future;
+use core::pin::{pin, Pin};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat, DisplayError};
+use embedded_graphics_core::primitives::Rectangle;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+
+use crate::models::AsyncFramebufferModel;
+
+/// Display interface which records the sent bytes and can be set to fail.
+pub struct MockDisplayInterface {
+    // Bytes sent since the last `clear`
+    log: [u8; 256],
+    log_len: usize,
+    failing: bool,
+    // Counts started transfers, shared with the test
+    transfers: Option<&'static AtomicUsize>,
+    // Transfers stay pending for one poll, like an interface waiting for DMA
+    pending: bool,
+}
+
+impl MockDisplayInterface {
+    pub fn new() -> Self {
+        Self {
+            log: [0; 256],
+            log_len: 0,
+            failing: false,
+            transfers: None,
+            pending: false,
+        }
+    }
+
+    /// Counts the started transfers in `transfers` and keeps each transfer pending for one poll.
+    pub fn with_pending_transfers(transfers: &'static AtomicUsize) -> Self {
+        Self {
+            transfers: Some(transfers),
+            pending: true,
+            ..Self::new()
+        }
+    }
+
+    /// Returns the bytes sent since the last call to [Self::clear].
+    pub fn log(&self) -> &[u8] {
+        &self.log[..self.log_len]
+    }
+
+    pub fn clear(&mut self) {
+        self.log_len = 0;
+    }
+
+    /// Makes all following transfers fail with a bus error.
+    pub fn set_failing(&mut self, failing: bool) {
+        self.failing = failing;
+    }
+
+    fn record(&mut self, data: DataFormat<'_>) -> Result<(), DisplayError> {
+        if self.failing {
+            return Err(DisplayError::BusWriteError);
+        }
+
+        match data {
+            DataFormat::U8(bytes) => bytes.iter().for_each(|byte| self.push(*byte)),
+            DataFormat::U8Iter(iter) => iter.for_each(|byte| self.push(byte)),
+            DataFormat::U16BE(words) => {
+                // interfaces are allowed to convert the buffer in place
+                for word in words.iter_mut() {
+                    *word = word.to_be();
+                    word.to_ne_bytes().iter().for_each(|byte| self.push(*byte));
+                }
+            }
+            DataFormat::U16BEIter(iter) => {
+                iter.for_each(|word| word.to_be_bytes().iter().for_each(|byte| self.push(*byte)))
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    async fn transfer(&mut self, data: DataFormat<'_>) -> Result<(), DisplayError> {
+        if let Some(transfers) = self.transfers {
+            transfers.fetch_add(1, Ordering::SeqCst);
+        }
+        if self.pending {
+            YieldOnce(false).await;
+        }
+
+        self.record(data)
+    }
+
+    fn push(&mut self, byte: u8) {
+        if let Some(entry) = self.log.get_mut(self.log_len) {
+            *entry = byte;
+            self.log_len += 1;
+        }
+    }
+}
+
+impl AsyncWriteOnlyDataCommand for MockDisplayInterface {
+    async fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.transfer(cmd).await
+    }
+
+    async fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.transfer(buf).await
+    }
+}
+
+// Future which returns `Pending` on the first poll.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+pub struct MockOutputPin;
+
+impl OutputPin for MockOutputPin {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+pub struct MockDelay;
+
+impl DelayNs for MockDelay {
+    async fn delay_ns(&mut self, _ns: u32) {}
+}
+
+/// Runs a future which never waits on anything to completion.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn noop(_: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+
+    // SAFETY: the vtable functions don't use the data pointer
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+/// Returns the next dirty area of `model` and marks it as flushed.
+pub fn take_dirty_area<M: AsyncFramebufferModel>(model: &mut M) -> Option<Rectangle> {
+    let area = model.dirty_area()?;
+    model.clear_dirty_area(&area);
+
+    Some(area)
+}
@@ -1,77 +1,194 @@
 //! Display models.
+//!
+//! The model types are shared with [mipidsi](https://crates.io/crates/mipidsi), this module
+//! implements [AsyncModel] for them.
 
 use crate::{
-    dcs::{Dcs, SetAddressMode},
+    dcs::{AsyncDcs, SetAddressMode},
     error::InitError,
     Error, ModelOptions,
 };
-use display_interface::WriteOnlyDataCommand;
-use embedded_graphics_core::prelude::RgbColor;
-use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+use display_interface::AsyncWriteOnlyDataCommand;
+use embedded_graphics_core::{prelude::RgbColor, primitives::Rectangle};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+
+pub use mipidsi::models::*;
+
+mod dirty_areas;
+mod double_buffer;
+mod packed_framebuffer;
 
 // existing model implementations
 mod gc9a01;
+mod hx8357d;
 mod ili9341;
 mod ili9342c;
 mod ili934x;
 mod ili9486;
+mod ili9488;
 mod st7735s;
 mod st7789;
+mod st7796s;
 
-pub use gc9a01::*;
-pub use ili9341::*;
-pub use ili9342c::*;
-pub use ili9486::*;
-pub use st7735s::*;
-pub use st7789::*;
+pub use double_buffer::*;
+pub use packed_framebuffer::*;
+pub use st7789::ST7789Framebuffer;
 
 /// Display model.
-pub trait Model {
+pub trait AsyncModel {
     /// The color format.
     type ColorFormat: RgbColor;
 
-    /// Initializes the display for this model with MADCTL from [crate::Display]
+    /// Delay in microseconds required after entering or leaving sleep mode
+    /// before the next command can be sent.
+    ///
+    /// Models set this to the value from the controller's datasheet. Most controllers need
+    /// 120 ms between leaving and entering sleep mode again, the delay must not be shorter than
+    /// that so [crate::AsyncDisplay::sleep] can directly follow [crate::AsyncDisplay::wake].
+    const SLEEP_DELAY_US: u32 = 120_000;
+
+    /// Initializes the display for this model with MADCTL from [crate::AsyncDisplay]
     /// and returns the value of MADCTL set by init
+    #[allow(async_fn_in_trait)]
     async fn init<RST, DELAY, DI>(
         &mut self,
-        dcs: &mut Dcs<DI>,
+        dcs: &mut AsyncDcs<DI>,
         delay: &mut DELAY,
         options: &ModelOptions,
         rst: &mut Option<RST>,
     ) -> Result<SetAddressMode, InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
+        DELAY: DelayNs,
         DI: AsyncWriteOnlyDataCommand;
 
     /// Resets the display using a reset pin.
-    fn hard_reset<RST, DELAY>(
+    #[allow(async_fn_in_trait)]
+    async fn hard_reset<RST, DELAY>(
         &mut self,
         rst: &mut RST,
         delay: &mut DELAY,
     ) -> Result<(), InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
+        DELAY: DelayNs,
     {
         rst.set_low().map_err(InitError::Pin)?;
-        delay.delay_us(10);
+        delay.delay_us(10).await;
         rst.set_high().map_err(InitError::Pin)?;
 
         Ok(())
     }
 
-    /// Writes pixels to the display IC via the given display interface.
+    /// Writes pixels to the display IC via the given async display interface.
     ///
     /// Any pixel color format conversion is done here.
-    fn write_pixels<DI, I>(&mut self, di: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    #[allow(async_fn_in_trait)]
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
     where
-        DI: WriteOnlyDataCommand,
+        DI: AsyncWriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>;
 
-    /// Creates default [ModelOptions] for this particular [Model].
+    /// Creates default [ModelOptions] for this particular [AsyncModel].
     ///
     /// This serves as a "sane default". There can be additional variants which will be provided via
     /// helper constructors.
     fn default_options() -> ModelOptions;
+
+    /// Applies the current [ModelOptions] to the model.
+    ///
+    /// Called by [crate::AsyncDisplay] after init and whenever the options change, e.g. on
+    /// orientation changes. Framebuffer models use this to pick up the oriented display size.
+    fn update_options(&mut self, _options: &ModelOptions) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Writes `colors` into the framebuffer on the MCU, if the model has one.
+    ///
+    /// Called by [crate::AsyncDisplay::set_pixels], which streams the pixels to the display
+    /// controller instead if this returns `false`. The default returns `false` without consuming
+    /// `colors`, framebuffer models return the result of [AsyncFramebufferModel::write_area].
+    fn write_framebuffer<I>(&mut self, _area: &Rectangle, _colors: &mut I) -> Result<bool, Error>
+    where
+        I: Iterator<Item = Self::ColorFormat>,
+    {
+        Ok(false)
+    }
+}
+
+/// Display model with a framebuffer on the MCU.
+///
+/// Drawing only modifies the framebuffer, the data is sent to the display by
+/// [crate::AsyncDisplay::flush].
+pub trait AsyncFramebufferModel: AsyncModel {
+    /// Fills the whole framebuffer with the given color.
+    fn clear(&mut self, color: Self::ColorFormat) -> Result<(), Error>;
+
+    /// Writes a pixel to the framebuffer.
+    ///
+    /// Any pixel color format conversion is done here.
+    fn write_pixel(&mut self, x: u16, y: u16, color: Self::ColorFormat) -> Result<(), Error>;
+
+    /// Writes `colors` into the given `area` of the framebuffer and marks it as modified.
+    ///
+    /// The colors are written row first starting at the top left corner and wrap around to the
+    /// top left corner once the area is filled, like a memory write to the controller. Pixels
+    /// outside of the framebuffer are skipped. Returns `true`, so it can be used to implement
+    /// [AsyncModel::write_framebuffer].
+    fn write_area<I>(&mut self, area: &Rectangle, colors: &mut I) -> Result<bool, Error>
+    where
+        I: Iterator<Item = Self::ColorFormat>,
+    {
+        let bottom_right = match area.bottom_right() {
+            Some(bottom_right) => bottom_right,
+            None => return Ok(true),
+        };
+        let (sx, sy) = (area.top_left.x as u16, area.top_left.y as u16);
+        let (ex, ey) = (bottom_right.x as u16, bottom_right.y as u16);
+
+        let (mut x, mut y) = (sx, sy);
+        for color in colors {
+            match self.write_pixel(x, y, color) {
+                Ok(()) | Err(Error::OutOfBoundsError) => {}
+                Err(error) => return Err(error),
+            }
+
+            if x < ex {
+                x += 1;
+            } else {
+                x = sx;
+                y = if y < ey { y + 1 } else { sy };
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Returns the next area modified since the last flush without removing it.
+    ///
+    /// Used by [crate::AsyncDisplay::flush] to only transfer the changed parts of a framebuffer.
+    fn dirty_area(&self) -> Option<Rectangle>;
+
+    /// Marks an area returned by [`AsyncFramebufferModel::dirty_area`] as sent to the display.
+    ///
+    /// Called by [crate::AsyncDisplay::flush] once the area was transferred successfully, areas
+    /// of a failed transfer stay dirty and are sent again by the next flush.
+    fn clear_dirty_area(&mut self, area: &Rectangle);
+
+    /// Actually transfer the data written by [`AsyncFramebufferModel::clear`] or
+    /// [`AsyncFramebufferModel::write_pixel`]
+    /// inside the given `area`.
+    ///
+    /// The address window for `area` is set by [crate::AsyncDisplay] before this is called.
+    #[allow(async_fn_in_trait)]
+    async fn flush<DI>(&mut self, dcs: &mut AsyncDcs<DI>, area: &Rectangle) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand;
+
+    /// Copies the areas of `other` which were modified since its last flush into this framebuffer.
+    ///
+    /// Used by [DoubleBuffer] to bring the back buffer up to date after a swap. The copied
+    /// areas are not marked as modified in this framebuffer.
+    fn copy_dirty_areas_from(&mut self, other: &Self);
 }
@@ -0,0 +1,200 @@
+//! Dirty area tracking for framebuffer models.
+
+use embedded_graphics_core::{
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+/// Max number of separately tracked dirty areas
+const MAX_DIRTY_AREAS: usize = 4;
+
+/// Inclusive area of pixels with start and end coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Area {
+    sx: u16,
+    sy: u16,
+    ex: u16,
+    ey: u16,
+}
+
+impl Area {
+    // Returns the smallest area containing both areas
+    fn union(&self, other: &Area) -> Area {
+        Area {
+            sx: self.sx.min(other.sx),
+            sy: self.sy.min(other.sy),
+            ex: self.ex.max(other.ex),
+            ey: self.ey.max(other.ey),
+        }
+    }
+
+    // Returns `true` if the areas overlap or share an edge
+    fn touches(&self, other: &Area) -> bool {
+        u32::from(self.sx) <= u32::from(other.ex) + 1
+            && u32::from(other.sx) <= u32::from(self.ex) + 1
+            && u32::from(self.sy) <= u32::from(other.ey) + 1
+            && u32::from(other.sy) <= u32::from(self.ey) + 1
+    }
+
+    fn pixel_count(&self) -> u32 {
+        (u32::from(self.ex - self.sx) + 1) * (u32::from(self.ey - self.sy) + 1)
+    }
+
+    fn to_rectangle(self) -> Rectangle {
+        Rectangle::new(
+            Point::new(i32::from(self.sx), i32::from(self.sy)),
+            Size::new(
+                u32::from(self.ex - self.sx) + 1,
+                u32::from(self.ey - self.sy) + 1,
+            ),
+        )
+    }
+}
+
+/// List of areas modified since the last flush.
+///
+/// Up to [MAX_DIRTY_AREAS] disjoint areas are tracked, touching areas are merged and once the list
+/// is full the new area is merged with the area that grows the least.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DirtyAreas {
+    areas: [Option<Area>; MAX_DIRTY_AREAS],
+}
+
+impl DirtyAreas {
+    /// Marks a single pixel as dirty.
+    pub(crate) fn add_pixel(&mut self, x: u16, y: u16) {
+        self.add(x, y, x, y);
+    }
+
+    /// Marks the area from `(sx, sy)` to `(ex, ey)` (inclusive) as dirty.
+    pub(crate) fn add(&mut self, sx: u16, sy: u16, ex: u16, ey: u16) {
+        let mut area = Area { sx, sy, ex, ey };
+
+        // merge with all touching areas, which might join previously disjoint areas
+        for slot in self.areas.iter_mut() {
+            if let Some(existing) = slot {
+                if existing.touches(&area) {
+                    area = existing.union(&area);
+                    *slot = None;
+                }
+            }
+        }
+
+        if let Some(slot) = self.areas.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(area);
+            return;
+        }
+
+        // list is full, merge with the area which grows the least
+        if let Some(slot) = self.areas.iter_mut().min_by_key(|slot| match slot {
+            Some(existing) => existing.union(&area).pixel_count() - existing.pixel_count(),
+            None => 0,
+        }) {
+            *slot = slot.map(|existing| existing.union(&area));
+        }
+    }
+
+    /// Returns the next dirty area without removing it.
+    pub(crate) fn peek(&self) -> Option<Rectangle> {
+        self.iter().next()
+    }
+
+    /// Marks an area returned by [Self::peek] as clean.
+    pub(crate) fn remove(&mut self, area: &Rectangle) {
+        if let Some(slot) = self
+            .areas
+            .iter_mut()
+            .find(|slot| slot.map(Area::to_rectangle).as_ref() == Some(area))
+        {
+            *slot = None;
+        }
+    }
+
+    /// Returns an iterator over all dirty areas without removing them.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = Rectangle> + '_ {
+        self.areas.iter().flatten().map(|area| area.to_rectangle())
+    }
+
+    /// Marks all areas as clean.
+    pub(crate) fn clear(&mut self) {
+        self.areas = [None; MAX_DIRTY_AREAS];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl DirtyAreas {
+        fn pop(&mut self) -> Option<Rectangle> {
+            let area = self.peek()?;
+            self.remove(&area);
+
+            Some(area)
+        }
+    }
+
+    fn rect(sx: i32, sy: i32, ex: i32, ey: i32) -> Rectangle {
+        Rectangle::with_corners(Point::new(sx, sy), Point::new(ex, ey))
+    }
+
+    #[test]
+    fn adjacent_pixels_are_merged() {
+        let mut dirty = DirtyAreas::default();
+        dirty.add_pixel(10, 10);
+        dirty.add_pixel(11, 10);
+        dirty.add_pixel(11, 11);
+
+        assert_eq!(dirty.pop(), Some(rect(10, 10, 11, 11)));
+        assert_eq!(dirty.pop(), None);
+    }
+
+    #[test]
+    fn disjoint_areas_are_kept_separate() {
+        let mut dirty = DirtyAreas::default();
+        dirty.add(0, 0, 9, 9);
+        dirty.add(100, 100, 109, 109);
+
+        assert_eq!(dirty.pop(), Some(rect(0, 0, 9, 9)));
+        assert_eq!(dirty.pop(), Some(rect(100, 100, 109, 109)));
+        assert_eq!(dirty.pop(), None);
+    }
+
+    #[test]
+    fn full_list_merges_closest_area() {
+        let mut dirty = DirtyAreas::default();
+        dirty.add_pixel(0, 0);
+        dirty.add_pixel(100, 0);
+        dirty.add_pixel(0, 100);
+        dirty.add_pixel(100, 100);
+        dirty.add_pixel(103, 100);
+
+        let mut areas = [None; 5];
+        for area in areas.iter_mut() {
+            *area = dirty.pop();
+        }
+
+        assert!(areas.contains(&Some(rect(100, 100, 103, 100))));
+        assert_eq!(areas[4], None);
+    }
+
+    #[test]
+    fn peek_keeps_area_until_removed() {
+        let mut dirty = DirtyAreas::default();
+        dirty.add(0, 0, 9, 9);
+
+        assert_eq!(dirty.peek(), Some(rect(0, 0, 9, 9)));
+        assert_eq!(dirty.peek(), Some(rect(0, 0, 9, 9)));
+        dirty.remove(&rect(0, 0, 9, 9));
+        assert_eq!(dirty.peek(), None);
+    }
+
+    #[test]
+    fn clear_removes_all_areas() {
+        let mut dirty = DirtyAreas::default();
+        dirty.add(0, 0, 9, 9);
+        dirty.clear();
+
+        assert_eq!(dirty.pop(), None);
+    }
+}
@@ -0,0 +1,180 @@
+use display_interface::AsyncWriteOnlyDataCommand;
+use embedded_graphics_core::primitives::Rectangle;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+
+use crate::{
+    dcs::{AsyncDcs, SetAddressMode},
+    error::InitError,
+    Error, ModelOptions,
+};
+
+use super::{AsyncFramebufferModel, AsyncModel, BrightnessControl};
+
+/// Double buffered framebuffer model.
+///
+/// Wraps two framebuffer models of the same type. Drawing goes into the back buffer while
+/// [crate::AsyncDisplay::flush] sends the front buffer, [DoubleBuffer::swap] exchanges them.
+///
+/// Use [crate::AsyncDisplay::swap_and_flush] to swap, flush and render the next frame in one
+/// call.
+pub struct DoubleBuffer<M> {
+    front: M,
+    back: M,
+}
+
+impl<M> DoubleBuffer<M>
+where
+    M: AsyncFramebufferModel,
+{
+    /// Creates a new double buffered model from two framebuffer models.
+    pub fn new(front: M, back: M) -> Self {
+        Self { front, back }
+    }
+
+    /// Exchanges the front and back buffer.
+    ///
+    /// The areas modified in the new front buffer are copied to the new back buffer,
+    /// so drawing can continue incrementally on top of the last frame.
+    pub fn swap(&mut self) {
+        core::mem::swap(&mut self.front, &mut self.back);
+        self.back.copy_dirty_areas_from(&self.front);
+    }
+
+    /// Returns the front and back buffer.
+    pub(crate) fn split_mut(&mut self) -> (&mut M, &mut M) {
+        (&mut self.front, &mut self.back)
+    }
+}
+
+impl<M> AsyncModel for DoubleBuffer<M>
+where
+    M: AsyncFramebufferModel,
+{
+    type ColorFormat = M::ColorFormat;
+    const SLEEP_DELAY_US: u32 = M::SLEEP_DELAY_US;
+
+    async fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut AsyncDcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        self.front.init(dcs, delay, options, rst).await
+    }
+
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        self.front.write_pixels(dcs, colors).await
+    }
+
+    fn default_options() -> ModelOptions {
+        M::default_options()
+    }
+
+    fn update_options(&mut self, options: &ModelOptions) -> Result<(), Error> {
+        self.front.update_options(options)?;
+        self.back.update_options(options)
+    }
+
+    fn write_framebuffer<I>(&mut self, area: &Rectangle, colors: &mut I) -> Result<bool, Error>
+    where
+        I: Iterator<Item = Self::ColorFormat>,
+    {
+        self.write_area(area, colors)
+    }
+}
+
+impl<M> BrightnessControl for DoubleBuffer<M> where M: BrightnessControl {}
+
+impl<M> AsyncFramebufferModel for DoubleBuffer<M>
+where
+    M: AsyncFramebufferModel,
+{
+    fn clear(&mut self, color: Self::ColorFormat) -> Result<(), Error> {
+        self.back.clear(color)
+    }
+
+    fn write_pixel(&mut self, x: u16, y: u16, color: Self::ColorFormat) -> Result<(), Error> {
+        self.back.write_pixel(x, y, color)
+    }
+
+    fn dirty_area(&self) -> Option<Rectangle> {
+        self.front.dirty_area()
+    }
+
+    fn clear_dirty_area(&mut self, area: &Rectangle) {
+        self.front.clear_dirty_area(area);
+    }
+
+    async fn flush<DI>(&mut self, dcs: &mut AsyncDcs<DI>, area: &Rectangle) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        self.front.flush(dcs, area).await
+    }
+
+    fn copy_dirty_areas_from(&mut self, other: &Self) {
+        self.front.copy_dirty_areas_from(&other.front);
+        self.back.copy_dirty_areas_from(&other.back);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics_core::{
+        pixelcolor::Rgb565,
+        prelude::{IntoStorage, Point, RgbColor, Size},
+    };
+
+    use super::*;
+    use crate::{mock::take_dirty_area, models::ST7789Framebuffer};
+
+    #[test]
+    fn swap_keeps_buffers_in_sync() -> Result<(), Error> {
+        let mut front = [0u16; 4 * 4];
+        let mut back = [0u16; 4 * 4];
+        let mut model = DoubleBuffer::new(
+            ST7789Framebuffer::new(&mut front),
+            ST7789Framebuffer::new(&mut back),
+        );
+        model.update_options(&ModelOptions::with_sizes((4, 4), (4, 4)))?;
+        let (front_model, back_model) = model.split_mut();
+        while take_dirty_area(front_model).is_some() {}
+        while take_dirty_area(back_model).is_some() {}
+
+        model.write_pixel(2, 1, Rgb565::RED)?;
+        assert_eq!(take_dirty_area(&mut model), None);
+
+        model.swap();
+        assert_eq!(
+            take_dirty_area(&mut model),
+            Some(Rectangle::new(Point::new(2, 1), Size::new(1, 1)))
+        );
+
+        // drawing continues on top of the previous frame
+        model.write_pixel(0, 0, Rgb565::GREEN)?;
+        model.swap();
+        assert_eq!(
+            take_dirty_area(&mut model),
+            Some(Rectangle::new(Point::new(0, 0), Size::new(1, 1)))
+        );
+        assert_eq!(take_dirty_area(&mut model), None);
+
+        for buffer in [front, back] {
+            assert_eq!(buffer[0], Rgb565::GREEN.into_storage());
+            assert_eq!(buffer[6], Rgb565::RED.into_storage());
+        }
+
+        Ok(())
+    }
+}
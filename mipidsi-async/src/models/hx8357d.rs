@@ -0,0 +1,193 @@
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat};
+use embedded_graphics_core::{
+    pixelcolor::{Rgb565, Rgb666},
+    prelude::{IntoStorage, RgbColor},
+};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+
+use crate::{
+    dcs::{
+        AsyncDcs, BitsPerPixel, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
+        SetDisplayOn, SetInvertMode, SetPixelFormat, SoftReset, WriteMemoryStart,
+    },
+    error::InitError,
+    AsyncBuilder, Error, ModelOptions,
+};
+
+use super::{AsyncModel, GammaControl, HX8357DGamma, Model};
+
+use super::{HX8357DRgb565, HX8357DRgb666};
+
+impl AsyncModel for HX8357DRgb565 {
+    type ColorFormat = Rgb565;
+    // SLPOUT needs 120 ms before the next command can be sent
+    const SLEEP_DELAY_US: u32 = 120_000;
+
+    async fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut AsyncDcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
+        }
+        delay.delay_us(10_000).await;
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common_async(dcs, delay, options, pf, self.gamma()).await?)
+    }
+
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart).await?;
+        let mut iter = colors.into_iter().map(|c| c.into_storage());
+
+        let buf = DataFormat::U16BEIter(&mut iter);
+        dcs.di.send_data(buf).await
+    }
+
+    fn default_options() -> ModelOptions {
+        <Self as Model>::default_options()
+    }
+}
+
+impl AsyncModel for HX8357DRgb666 {
+    type ColorFormat = Rgb666;
+    // SLPOUT needs 120 ms before the next command can be sent
+    const SLEEP_DELAY_US: u32 = 120_000;
+
+    async fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut AsyncDcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
+        }
+        delay.delay_us(10_000).await;
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common_async(dcs, delay, options, pf, self.gamma()).await?)
+    }
+
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart).await?;
+        let mut iter = colors.into_iter().flat_map(|c| {
+            let red = c.r() << 2;
+            let green = c.g() << 2;
+            let blue = c.b() << 2;
+            [red, green, blue]
+        });
+
+        let buf = DataFormat::U8Iter(&mut iter);
+        dcs.di.send_data(buf).await
+    }
+
+    fn default_options() -> ModelOptions {
+        <Self as Model>::default_options()
+    }
+}
+
+// simplified constructor for AsyncDisplay
+
+impl<DI> AsyncBuilder<DI, HX8357DRgb565>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for a HX8357D display in Rgb565 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn hx8357d_rgb565(di: DI) -> Self {
+        Self::with_model(di, HX8357DRgb565::default())
+    }
+}
+
+impl<DI> AsyncBuilder<DI, HX8357DRgb666>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for a HX8357D display in Rgb666 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn hx8357d_rgb666(di: DI) -> Self {
+        Self::with_model(di, HX8357DRgb666::default())
+    }
+}
+
+// common init for all color format models using an async interface
+async fn init_common_async<DELAY, DI>(
+    dcs: &mut AsyncDcs<DI>,
+    delay: &mut DELAY,
+    options: &ModelOptions,
+    pixel_format: PixelFormat,
+    gamma: &HX8357DGamma,
+) -> Result<SetAddressMode, Error>
+where
+    DELAY: DelayNs,
+    DI: AsyncWriteOnlyDataCommand,
+{
+    let madctl = SetAddressMode::from(options);
+
+    dcs.write_raw(0xB9, &[0xFF, 0x83, 0x57]).await?; // SETC, enable extended commands
+    delay.delay_us(300_000).await;
+
+    dcs.write_raw(0xB3, &[0x80, 0x00, 0x06, 0x06]).await?; // SETRGB, enable SDO pin
+    dcs.write_raw(0xB6, &[0x25]).await?; // SETCOM, VCOMDC -1.52V
+    dcs.write_raw(0xB0, &[0x68]).await?; // SETOSC, normal mode 70Hz, idle mode 55Hz
+    dcs.write_raw(0xCC, &[0x05]).await?; // SETPANEL, BGR panel, gate direction swapped
+    dcs.write_raw(0xB1, &[0x00, 0x15, 0x1C, 0x1C, 0x83, 0xAA])
+        .await?; // SETPWR1, power control
+    dcs.write_raw(0xC0, &[0x50, 0x50, 0x01, 0x3C, 0x1E, 0x08])
+        .await?; // SETSTBA, source driver timing
+    dcs.write_raw(0xB4, &[0x02, 0x40, 0x00, 0x2A, 0x2A, 0x0D, 0x78])
+        .await?; // SETCYC, display cycle
+    dcs.write_raw(0xE0, &gamma.parameters()).await?; // SETGAMMA, gamma curve
+
+    dcs.write_command(SetPixelFormat::new(pixel_format)).await?; // pixel format
+    dcs.write_command(madctl).await?; // set memory data access control
+    dcs.write_command(SetInvertMode(options.invert_colors()))
+        .await?;
+
+    dcs.write_command(ExitSleepMode).await?; // turn off sleep
+    delay.delay_us(150_000).await;
+
+    dcs.write_command(EnterNormalMode).await?; // turn to normal mode
+    dcs.write_command(SetDisplayOn).await?; // turn on display
+    delay.delay_us(50_000).await;
+
+    Ok(madctl)
+}
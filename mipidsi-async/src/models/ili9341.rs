@@ -1,99 +1,104 @@
-use display_interface::WriteOnlyDataCommand;
+use display_interface::AsyncWriteOnlyDataCommand;
 use embedded_graphics_core::pixelcolor::{Rgb565, Rgb666};
-use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
 
 use crate::{
-    dcs::{BitsPerPixel, Dcs, PixelFormat, SetAddressMode, SoftReset},
+    dcs::{AsyncDcs, BitsPerPixel, PixelFormat, SetAddressMode, SoftReset},
     error::InitError,
-    models::{ili934x, Model},
-    Builder, Error, ModelOptions,
+    models::{ili934x, AsyncModel, Model, Rgb666Framebuffer, Rgb888Framebuffer},
+    AsyncBuilder, Error, ModelOptions,
 };
 
-/// ILI9341 display in Rgb565 color mode.
-pub struct ILI9341Rgb565;
+use super::{ILI9341Rgb565, ILI9341Rgb666};
 
-/// ILI9341 display in Rgb666 color mode.
-pub struct ILI9341Rgb666;
-
-impl Model for ILI9341Rgb565 {
+impl AsyncModel for ILI9341Rgb565 {
     type ColorFormat = Rgb565;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
 
-    fn init<RST, DELAY, DI>(
+    async fn init<RST, DELAY, DI>(
         &mut self,
-        dcs: &mut Dcs<DI>,
+        dcs: &mut AsyncDcs<DI>,
         delay: &mut DELAY,
         options: &ModelOptions,
         rst: &mut Option<RST>,
     ) -> Result<SetAddressMode, InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
-        DI: WriteOnlyDataCommand,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
     {
         match rst {
-            Some(ref mut rst) => self.hard_reset(rst, delay)?,
-            None => dcs.write_command(SoftReset)?,
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
         }
 
         let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
-        ili934x::init_common(dcs, delay, options, pf).map_err(Into::into)
+        ili934x::init_common_async(dcs, delay, options, pf)
+            .await
+            .map_err(Into::into)
     }
 
-    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
     where
-        DI: WriteOnlyDataCommand,
+        DI: AsyncWriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>,
     {
-        ili934x::write_pixels_rgb565(dcs, colors)
+        ili934x::write_pixels_rgb565_async(dcs, colors).await
     }
 
     fn default_options() -> ModelOptions {
-        ModelOptions::with_sizes((240, 320), (240, 320))
+        <Self as Model>::default_options()
     }
 }
 
-impl Model for ILI9341Rgb666 {
+impl AsyncModel for ILI9341Rgb666 {
     type ColorFormat = Rgb666;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
 
-    fn init<RST, DELAY, DI>(
+    async fn init<RST, DELAY, DI>(
         &mut self,
-        dcs: &mut Dcs<DI>,
+        dcs: &mut AsyncDcs<DI>,
         delay: &mut DELAY,
         options: &ModelOptions,
         rst: &mut Option<RST>,
     ) -> Result<SetAddressMode, InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
-        DI: WriteOnlyDataCommand,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
     {
         match rst {
-            Some(ref mut rst) => self.hard_reset(rst, delay)?,
-            None => dcs.write_command(SoftReset)?,
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
         }
 
         let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
-        ili934x::init_common(dcs, delay, options, pf).map_err(Into::into)
+        ili934x::init_common_async(dcs, delay, options, pf)
+            .await
+            .map_err(Into::into)
     }
 
-    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
     where
-        DI: WriteOnlyDataCommand,
+        DI: AsyncWriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>,
     {
-        ili934x::write_pixels_rgb666(dcs, colors)
+        ili934x::write_pixels_rgb666_async(dcs, colors).await
     }
 
     fn default_options() -> ModelOptions {
-        ModelOptions::with_sizes((240, 320), (240, 320))
+        <Self as Model>::default_options()
     }
 }
 
-// simplified constructor for Display
+// simplified constructor for AsyncDisplay
 
-impl<DI> Builder<DI, ILI9341Rgb565>
+impl<DI> AsyncBuilder<DI, ILI9341Rgb565>
 where
-    DI: WriteOnlyDataCommand,
+    DI: AsyncWriteOnlyDataCommand,
 {
     /// Creates a new display builder for an ILI9341 display in Rgb565 color mode.
     ///
@@ -105,26 +110,64 @@ where
     ///
     /// # Arguments
     ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
     ///
     pub fn ili9341_rgb565(di: DI) -> Self {
         Self::with_model(di, ILI9341Rgb565)
     }
 }
 
-impl<DI> Builder<DI, ILI9341Rgb666>
+impl<DI> AsyncBuilder<DI, ILI9341Rgb666>
 where
-    DI: WriteOnlyDataCommand,
+    DI: AsyncWriteOnlyDataCommand,
 {
-    /// Creates a new display builder for an ILI9341 display in Rgb565 color mode.
+    /// Creates a new display builder for an ILI9341 display in Rgb666 color mode.
     ///
     /// The default framebuffer size and display size is 240x320 pixels.
     ///
     /// # Arguments
     ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
     ///
     pub fn ili9341_rgb666(di: DI) -> Self {
         Self::with_model(di, ILI9341Rgb666)
     }
 }
+
+impl<'framebuffer, DI> AsyncBuilder<DI, Rgb666Framebuffer<'framebuffer, ILI9341Rgb666>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9341 display in Rgb666 color mode with a integrated
+    /// framebuffer.
+    ///
+    /// The default framebuffer size and display size is 240x320 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `framebuffer` - the framebuffer to store the data, needs to hold at least `width * height * 3` bytes
+    ///   of the display size
+    pub fn ili9341_rgb666_framebuffer(di: DI, framebuffer: &'framebuffer mut [u8]) -> Self {
+        Self::with_model(di, Rgb666Framebuffer::new(ILI9341Rgb666, framebuffer))
+    }
+}
+
+impl<'framebuffer, DI> AsyncBuilder<DI, Rgb888Framebuffer<'framebuffer, ILI9341Rgb666>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9341 display in Rgb666 color mode with a integrated
+    /// framebuffer storing Rgb888 colors.
+    ///
+    /// The default framebuffer size and display size is 240x320 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `framebuffer` - the framebuffer to store the data, needs to hold at least `width * height * 3` bytes
+    ///   of the display size
+    pub fn ili9341_rgb888_framebuffer(di: DI, framebuffer: &'framebuffer mut [u8]) -> Self {
+        Self::with_model(di, Rgb888Framebuffer::new(ILI9341Rgb666, framebuffer))
+    }
+}
@@ -1,101 +1,102 @@
-use display_interface::{DataFormat, WriteOnlyDataCommand};
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat};
 use embedded_graphics_core::{
     pixelcolor::{Rgb565, Rgb666},
     prelude::{IntoStorage, RgbColor},
 };
-use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
 
 use crate::{
     dcs::{
-        BitsPerPixel, Dcs, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
+        AsyncDcs, BitsPerPixel, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
         SetDisplayOn, SetInvertMode, SetPixelFormat, SoftReset, WriteMemoryStart,
     },
     error::InitError,
-    Builder, Error, ModelOptions,
+    AsyncBuilder, Error, ModelOptions,
 };
 
-use super::Model;
+use super::{AsyncModel, Model, Rgb666Framebuffer, Rgb888Framebuffer};
 
-/// ILI9486 display in Rgb565 color mode.
-pub struct ILI9486Rgb565;
+use super::{ILI9486Rgb565, ILI9486Rgb666};
 
-/// ILI9486 display in Rgb666 color mode.
-pub struct ILI9486Rgb666;
-
-impl Model for ILI9486Rgb565 {
+impl AsyncModel for ILI9486Rgb565 {
     type ColorFormat = Rgb565;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
 
-    fn init<RST, DELAY, DI>(
+    async fn init<RST, DELAY, DI>(
         &mut self,
-        dcs: &mut Dcs<DI>,
+        dcs: &mut AsyncDcs<DI>,
         delay: &mut DELAY,
         options: &ModelOptions,
         rst: &mut Option<RST>,
     ) -> Result<SetAddressMode, InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
-        DI: WriteOnlyDataCommand,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
     {
         match rst {
-            Some(ref mut rst) => self.hard_reset(rst, delay)?,
-            None => dcs.write_command(SoftReset)?,
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
         }
-        delay.delay_us(120_000);
+        delay.delay_us(120_000).await;
 
         let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
-        Ok(init_common(dcs, delay, options, pf)?)
+        Ok(init_common_async(dcs, delay, options, pf).await?)
     }
 
-    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
     where
-        DI: WriteOnlyDataCommand,
+        DI: AsyncWriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>,
     {
-        dcs.write_command(WriteMemoryStart)?;
+        dcs.write_command(WriteMemoryStart).await?;
         let mut iter = colors.into_iter().map(|c| c.into_storage());
 
         let buf = DataFormat::U16BEIter(&mut iter);
-        dcs.di.send_data(buf)
+        dcs.di.send_data(buf).await
     }
 
     fn default_options() -> ModelOptions {
-        ModelOptions::with_sizes((320, 480), (320, 480))
+        <Self as Model>::default_options()
     }
 }
 
-impl Model for ILI9486Rgb666 {
+impl AsyncModel for ILI9486Rgb666 {
     type ColorFormat = Rgb666;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
 
-    fn init<RST, DELAY, DI>(
+    async fn init<RST, DELAY, DI>(
         &mut self,
-        dcs: &mut Dcs<DI>,
+        dcs: &mut AsyncDcs<DI>,
         delay: &mut DELAY,
         options: &ModelOptions,
         rst: &mut Option<RST>,
     ) -> Result<SetAddressMode, InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
-        DI: WriteOnlyDataCommand,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
     {
         match rst {
-            Some(ref mut rst) => self.hard_reset(rst, delay)?,
-            None => dcs.write_command(SoftReset)?,
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
         };
 
-        delay.delay_us(120_000);
+        delay.delay_us(120_000).await;
 
         let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
-        Ok(init_common(dcs, delay, options, pf)?)
+        Ok(init_common_async(dcs, delay, options, pf).await?)
     }
 
-    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
     where
-        DI: WriteOnlyDataCommand,
+        DI: AsyncWriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>,
     {
-        dcs.write_command(WriteMemoryStart)?;
+        dcs.write_command(WriteMemoryStart).await?;
         let mut iter = colors.into_iter().flat_map(|c| {
             let red = c.r() << 2;
             let green = c.g() << 2;
@@ -104,19 +105,19 @@ impl Model for ILI9486Rgb666 {
         });
 
         let buf = DataFormat::U8Iter(&mut iter);
-        dcs.di.send_data(buf)
+        dcs.di.send_data(buf).await
     }
 
     fn default_options() -> ModelOptions {
-        ModelOptions::with_sizes((320, 480), (320, 480))
+        <Self as Model>::default_options()
     }
 }
 
-// simplified constructor for Display
+// simplified constructor for AsyncDisplay
 
-impl<DI> Builder<DI, ILI9486Rgb565>
+impl<DI> AsyncBuilder<DI, ILI9486Rgb565>
 where
-    DI: WriteOnlyDataCommand,
+    DI: AsyncWriteOnlyDataCommand,
 {
     /// Creates a new display builder for an ILI9486 display in Rgb565 color mode.
     ///
@@ -128,16 +129,16 @@ where
     ///
     /// # Arguments
     ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
     ///
     pub fn ili9486_rgb565(di: DI) -> Self {
         Self::with_model(di, ILI9486Rgb565)
     }
 }
 
-impl<DI> Builder<DI, ILI9486Rgb666>
+impl<DI> AsyncBuilder<DI, ILI9486Rgb666>
 where
-    DI: WriteOnlyDataCommand,
+    DI: AsyncWriteOnlyDataCommand,
 {
     /// Creates a new display builder for ILI9486 displays in Rgb666 color mode.
     ///
@@ -145,42 +146,75 @@ where
     ///
     /// # Arguments
     ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
     ///
     pub fn ili9486_rgb666(di: DI) -> Self {
         Self::with_model(di, ILI9486Rgb666)
     }
 }
 
-// common init for all color format models
-fn init_common<DELAY, DI>(
-    dcs: &mut Dcs<DI>,
+impl<'framebuffer, DI> AsyncBuilder<DI, Rgb666Framebuffer<'framebuffer, ILI9486Rgb666>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9486 display in Rgb666 color mode with a integrated
+    /// framebuffer.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `framebuffer` - the framebuffer to store the data, needs to hold at least `width * height * 3` bytes
+    ///   of the display size
+    pub fn ili9486_rgb666_framebuffer(di: DI, framebuffer: &'framebuffer mut [u8]) -> Self {
+        Self::with_model(di, Rgb666Framebuffer::new(ILI9486Rgb666, framebuffer))
+    }
+}
+
+impl<'framebuffer, DI> AsyncBuilder<DI, Rgb888Framebuffer<'framebuffer, ILI9486Rgb666>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9486 display in Rgb666 color mode with a integrated
+    /// framebuffer storing Rgb888 colors.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `framebuffer` - the framebuffer to store the data, needs to hold at least `width * height * 3` bytes
+    ///   of the display size
+    pub fn ili9486_rgb888_framebuffer(di: DI, framebuffer: &'framebuffer mut [u8]) -> Self {
+        Self::with_model(di, Rgb888Framebuffer::new(ILI9486Rgb666, framebuffer))
+    }
+}
+
+// common init for all color format models using an async interface
+async fn init_common_async<DELAY, DI>(
+    dcs: &mut AsyncDcs<DI>,
     delay: &mut DELAY,
     options: &ModelOptions,
     pixel_format: PixelFormat,
 ) -> Result<SetAddressMode, Error>
 where
-    DELAY: DelayUs<u32>,
-    DI: WriteOnlyDataCommand,
+    DELAY: DelayNs,
+    DI: AsyncWriteOnlyDataCommand,
 {
     let madctl = SetAddressMode::from(options);
-    dcs.write_command(ExitSleepMode)?; // turn off sleep
-    dcs.write_command(SetPixelFormat::new(pixel_format))?; // pixel format
-    dcs.write_command(madctl)?; // left -> right, bottom -> top RGB
-                                // dcs.write_command(Instruction::VCMOFSET, &[0x00, 0x48, 0x00, 0x48])?; //VCOM  Control 1 [00 40 00 40]
-                                // dcs.write_command(Instruction::INVCO, &[0x0])?; //Inversion Control [00]
-    dcs.write_command(SetInvertMode(options.invert_colors))?;
-
-    // optional gamma setup
-    // dcs.write_raw(Instruction::PGC, &[0x00, 0x2C, 0x2C, 0x0B, 0x0C, 0x04, 0x4C, 0x64, 0x36, 0x03, 0x0E, 0x01, 0x10, 0x01, 0x00])?; // Positive Gamma Control
-    // dcs.write_raw(Instruction::NGC, &[0x0F, 0x37, 0x37, 0x0C, 0x0F, 0x05, 0x50, 0x32, 0x36, 0x04, 0x0B, 0x00, 0x19, 0x14, 0x0F])?; // Negative Gamma Control
+    dcs.write_command(ExitSleepMode).await?; // turn off sleep
+    dcs.write_command(SetPixelFormat::new(pixel_format)).await?; // pixel format
+    dcs.write_command(madctl).await?; // left -> right, bottom -> top RGB
+    dcs.write_command(SetInvertMode(options.invert_colors()))
+        .await?;
 
-    dcs.write_raw(0xB6, &[0b0000_0010, 0x02, 0x3B])?; // DFC
-    dcs.write_command(EnterNormalMode)?; // turn to normal mode
-    dcs.write_command(SetDisplayOn)?; // turn on display
+    dcs.write_raw(0xB6, &[0b0000_0010, 0x02, 0x3B]).await?; // DFC
+    dcs.write_command(EnterNormalMode).await?; // turn to normal mode
+    dcs.write_command(SetDisplayOn).await?; // turn on display
 
     // DISPON requires some time otherwise we risk SPI data issues
-    delay.delay_us(120_000);
+    delay.delay_us(120_000).await;
 
     Ok(madctl)
 }
@@ -0,0 +1,242 @@
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat};
+use embedded_graphics_core::{
+    pixelcolor::{Rgb565, Rgb666},
+    prelude::{IntoStorage, RgbColor},
+};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+
+use crate::{
+    dcs::{
+        AsyncDcs, BitsPerPixel, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
+        SetDisplayOn, SetInvertMode, SetPixelFormat, SoftReset, WriteMemoryStart,
+    },
+    error::InitError,
+    AsyncBuilder, Error, ModelOptions,
+};
+
+use super::{AsyncModel, GammaControl, ILI9488Gamma, Model, Rgb666Framebuffer, Rgb888Framebuffer};
+
+use super::{ILI9488Rgb565, ILI9488Rgb666};
+
+impl AsyncModel for ILI9488Rgb565 {
+    type ColorFormat = Rgb565;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
+
+    async fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut AsyncDcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
+        }
+        delay.delay_us(120_000).await;
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common_async(dcs, delay, options, pf, self.gamma()).await?)
+    }
+
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart).await?;
+        let mut iter = colors.into_iter().map(|c| c.into_storage());
+
+        let buf = DataFormat::U16BEIter(&mut iter);
+        dcs.di.send_data(buf).await
+    }
+
+    fn default_options() -> ModelOptions {
+        <Self as Model>::default_options()
+    }
+}
+
+impl AsyncModel for ILI9488Rgb666 {
+    type ColorFormat = Rgb666;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
+
+    async fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut AsyncDcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
+        }
+        delay.delay_us(120_000).await;
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common_async(dcs, delay, options, pf, self.gamma()).await?)
+    }
+
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart).await?;
+        let mut iter = colors.into_iter().flat_map(|c| {
+            let red = c.r() << 2;
+            let green = c.g() << 2;
+            let blue = c.b() << 2;
+            [red, green, blue]
+        });
+
+        let buf = DataFormat::U8Iter(&mut iter);
+        dcs.di.send_data(buf).await
+    }
+
+    fn default_options() -> ModelOptions {
+        <Self as Model>::default_options()
+    }
+}
+
+// simplified constructor for AsyncDisplay
+
+impl<DI> AsyncBuilder<DI, ILI9488Rgb565>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9488 display in Rgb565 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Limitations
+    ///
+    /// The ILI9488 only supports 18 bit pixels over SPI, use [`AsyncBuilder::ili9488_rgb666`] for
+    /// displays with SPI connection. The Rgb565 color mode can only be used with a parallel
+    /// interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn ili9488_rgb565(di: DI) -> Self {
+        Self::with_model(di, ILI9488Rgb565::default())
+    }
+}
+
+impl<DI> AsyncBuilder<DI, ILI9488Rgb666>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9488 display in Rgb666 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn ili9488_rgb666(di: DI) -> Self {
+        Self::with_model(di, ILI9488Rgb666::default())
+    }
+}
+
+impl<'framebuffer, DI> AsyncBuilder<DI, Rgb666Framebuffer<'framebuffer, ILI9488Rgb666>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9488 display in Rgb666 color mode with a integrated
+    /// framebuffer.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `framebuffer` - the framebuffer to store the data, needs to hold at least `width * height * 3` bytes
+    ///   of the display size
+    pub fn ili9488_rgb666_framebuffer(di: DI, framebuffer: &'framebuffer mut [u8]) -> Self {
+        Self::with_model(
+            di,
+            Rgb666Framebuffer::new(ILI9488Rgb666::default(), framebuffer),
+        )
+    }
+}
+
+impl<'framebuffer, DI> AsyncBuilder<DI, Rgb888Framebuffer<'framebuffer, ILI9488Rgb666>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9488 display in Rgb666 color mode with a integrated
+    /// framebuffer storing Rgb888 colors.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `framebuffer` - the framebuffer to store the data, needs to hold at least `width * height * 3` bytes
+    ///   of the display size
+    pub fn ili9488_rgb888_framebuffer(di: DI, framebuffer: &'framebuffer mut [u8]) -> Self {
+        Self::with_model(
+            di,
+            Rgb888Framebuffer::new(ILI9488Rgb666::default(), framebuffer),
+        )
+    }
+}
+
+// common init for all color format models using an async interface
+async fn init_common_async<DELAY, DI>(
+    dcs: &mut AsyncDcs<DI>,
+    delay: &mut DELAY,
+    options: &ModelOptions,
+    pixel_format: PixelFormat,
+    gamma: &ILI9488Gamma,
+) -> Result<SetAddressMode, Error>
+where
+    DELAY: DelayNs,
+    DI: AsyncWriteOnlyDataCommand,
+{
+    let madctl = SetAddressMode::from(options);
+
+    dcs.write_raw(0xE0, &gamma.positive).await?; // positive gamma control
+    dcs.write_raw(0xE1, &gamma.negative).await?; // negative gamma control
+    dcs.write_raw(0xC0, &[0x17, 0x15]).await?; // power control 1
+    dcs.write_raw(0xC1, &[0x41]).await?; // power control 2
+    dcs.write_raw(0xC5, &[0x00, 0x12, 0x80]).await?; // VCOM control
+
+    dcs.write_command(madctl).await?; // set memory data access control
+    dcs.write_command(SetPixelFormat::new(pixel_format)).await?; // pixel format
+    dcs.write_raw(0xB0, &[0x00]).await?; // interface mode control
+    dcs.write_raw(0xB1, &[0xA0]).await?; // frame rate control, 60Hz
+    dcs.write_raw(0xB4, &[0x02]).await?; // 2-dot inversion control
+    dcs.write_raw(0xB6, &[0x02, 0x02, 0x3B]).await?; // DFC
+    dcs.write_raw(0xB7, &[0xC6]).await?; // entry mode set
+    dcs.write_raw(0xF7, &[0xA9, 0x51, 0x2C, 0x82]).await?; // adjust control 3
+    dcs.write_command(SetInvertMode(options.invert_colors()))
+        .await?;
+
+    dcs.write_command(ExitSleepMode).await?; // turn off sleep
+    delay.delay_us(120_000).await;
+
+    dcs.write_command(EnterNormalMode).await?; // turn to normal mode
+    dcs.write_command(SetDisplayOn).await?; // turn on display
+
+    // DISPON requires some time otherwise we risk SPI data issues
+    delay.delay_us(120_000).await;
+
+    Ok(madctl)
+}
@@ -0,0 +1,301 @@
+use core::marker::PhantomData;
+
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat};
+use embedded_graphics_core::{
+    pixelcolor::{Rgb666, Rgb888},
+    prelude::RgbColor,
+    primitives::Rectangle,
+};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+
+use crate::{
+    dcs::{AsyncDcs, SetAddressMode, WriteMemoryStart},
+    error::InitError,
+    Error, ModelOptions,
+};
+
+use super::{dirty_areas::DirtyAreas, AsyncFramebufferModel, AsyncModel, BrightnessControl};
+
+/// Number of bytes per pixel in the framebuffer.
+const BYTES_PER_PIXEL: usize = 3;
+
+/// Color which is stored as 3 bytes per pixel in the wire format of 18 bit controllers.
+pub trait PackedColor: RgbColor {
+    /// Returns the red, green and blue bytes sent to the controller.
+    fn to_bytes(self) -> [u8; 3];
+
+    /// Converts the color into the color format of the controller.
+    fn to_rgb666(self) -> Rgb666;
+}
+
+impl PackedColor for Rgb666 {
+    fn to_bytes(self) -> [u8; 3] {
+        // 6 bit channels are sent in the upper bits of each byte
+        [self.r() << 2, self.g() << 2, self.b() << 2]
+    }
+
+    fn to_rgb666(self) -> Rgb666 {
+        self
+    }
+}
+
+impl PackedColor for Rgb888 {
+    fn to_bytes(self) -> [u8; 3] {
+        // the controller ignores the 2 lower bits of each byte
+        [self.r(), self.g(), self.b()]
+    }
+
+    fn to_rgb666(self) -> Rgb666 {
+        Rgb666::from(self)
+    }
+}
+
+/// Framebuffer model for controllers running in 18 bit color mode.
+///
+/// Wraps a streaming [AsyncModel] in Rgb666 color mode and stores 3 bytes per pixel on the MCU
+/// in the wire format of the controller. Data only gets sent to the display with a call to
+/// [crate::AsyncDisplay::flush].
+///
+/// The framebuffer needs to hold at least `width * height * 3` bytes of the configured display size.
+pub struct PackedFramebuffer<'framebuffer, M, C> {
+    model: M,
+    framebuffer: &'framebuffer mut [u8],
+    // Display size (w, h) in the current orientation
+    size: (u16, u16),
+    // Areas modified since the last flush
+    dirty: DirtyAreas,
+    color: PhantomData<C>,
+}
+
+/// Framebuffer model storing [Rgb666] colors, see [PackedFramebuffer].
+pub type Rgb666Framebuffer<'framebuffer, M> = PackedFramebuffer<'framebuffer, M, Rgb666>;
+
+/// Framebuffer model storing [Rgb888] colors, see [PackedFramebuffer].
+///
+/// The lower 2 bits of each channel are ignored by the controller.
+pub type Rgb888Framebuffer<'framebuffer, M> = PackedFramebuffer<'framebuffer, M, Rgb888>;
+
+impl<'framebuffer, M, C> PackedFramebuffer<'framebuffer, M, C>
+where
+    M: AsyncModel<ColorFormat = Rgb666>,
+    C: PackedColor,
+{
+    /// Creates a new framebuffer model for `model` backed by the given `framebuffer`.
+    pub fn new(model: M, framebuffer: &'framebuffer mut [u8]) -> Self {
+        Self {
+            model,
+            framebuffer,
+            size: (0, 0),
+            dirty: DirtyAreas::default(),
+            color: PhantomData,
+        }
+    }
+
+    // Number of framebuffer bytes used by the current display size
+    fn byte_count(&self) -> usize {
+        usize::from(self.size.0) * usize::from(self.size.1) * BYTES_PER_PIXEL
+    }
+
+    // Marks the whole display as dirty
+    fn mark_all_dirty(&mut self) {
+        let (width, height) = self.size;
+        if width > 0 && height > 0 {
+            self.dirty.clear();
+            self.dirty.add(0, 0, width - 1, height - 1);
+        }
+    }
+
+    // Byte range of the given row section in the framebuffer
+    fn row_range(&self, x: usize, y: usize, width: usize) -> core::ops::Range<usize> {
+        let start = (y * usize::from(self.size.0) + x) * BYTES_PER_PIXEL;
+        start..start + width * BYTES_PER_PIXEL
+    }
+}
+
+impl<'framebuffer, M, C> AsyncModel for PackedFramebuffer<'framebuffer, M, C>
+where
+    M: AsyncModel<ColorFormat = Rgb666>,
+    C: PackedColor,
+{
+    type ColorFormat = C;
+    const SLEEP_DELAY_US: u32 = M::SLEEP_DELAY_US;
+
+    async fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut AsyncDcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        self.model.init(dcs, delay, options, rst).await
+    }
+
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        self.model
+            .write_pixels(dcs, colors.into_iter().map(C::to_rgb666))
+            .await
+    }
+
+    fn default_options() -> ModelOptions {
+        M::default_options()
+    }
+
+    fn update_options(&mut self, options: &ModelOptions) -> Result<(), Error> {
+        let (width, height) = options.display_size();
+        if usize::from(width) * usize::from(height) * BYTES_PER_PIXEL > self.framebuffer.len() {
+            return Err(Error::OutOfBoundsError);
+        }
+
+        if self.size != (width, height) {
+            self.size = (width, height);
+            self.mark_all_dirty();
+        }
+
+        self.model.update_options(options)
+    }
+
+    fn write_framebuffer<I>(&mut self, area: &Rectangle, colors: &mut I) -> Result<bool, Error>
+    where
+        I: Iterator<Item = Self::ColorFormat>,
+    {
+        self.write_area(area, colors)
+    }
+}
+
+impl<M, C> BrightnessControl for PackedFramebuffer<'_, M, C> where M: BrightnessControl {}
+
+impl<'framebuffer, M, C> AsyncFramebufferModel for PackedFramebuffer<'framebuffer, M, C>
+where
+    M: AsyncModel<ColorFormat = Rgb666>,
+    C: PackedColor,
+{
+    fn clear(&mut self, color: Self::ColorFormat) -> Result<(), Error> {
+        let bytes = color.to_bytes();
+        let byte_count = self.byte_count();
+        for pixel in self.framebuffer[..byte_count].chunks_exact_mut(BYTES_PER_PIXEL) {
+            pixel.copy_from_slice(&bytes);
+        }
+        self.mark_all_dirty();
+
+        Ok(())
+    }
+
+    fn write_pixel(&mut self, x: u16, y: u16, color: Self::ColorFormat) -> Result<(), Error> {
+        let (width, height) = self.size;
+        if x >= width || y >= height {
+            return Err(Error::OutOfBoundsError);
+        }
+
+        let range = self.row_range(usize::from(x), usize::from(y), 1);
+        self.framebuffer[range].copy_from_slice(&color.to_bytes());
+        self.dirty.add_pixel(x, y);
+
+        Ok(())
+    }
+
+    fn dirty_area(&self) -> Option<Rectangle> {
+        self.dirty.peek()
+    }
+
+    fn clear_dirty_area(&mut self, area: &Rectangle) {
+        self.dirty.remove(area);
+    }
+
+    async fn flush<DI>(&mut self, dcs: &mut AsyncDcs<DI>, area: &Rectangle) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        let sx = area.top_left.x as usize;
+        let sy = area.top_left.y as usize;
+        let area_width = area.size.width as usize;
+        let area_height = area.size.height as usize;
+
+        dcs.write_command(WriteMemoryStart).await?;
+
+        if area_width == usize::from(self.size.0) {
+            // full rows are contiguous in the framebuffer
+            let range = self.row_range(0, sy, area_width * area_height);
+            dcs.di
+                .send_data(DataFormat::U8(&self.framebuffer[range]))
+                .await?;
+        } else {
+            for y in sy..sy + area_height {
+                let range = self.row_range(sx, y, area_width);
+                dcs.di
+                    .send_data(DataFormat::U8(&self.framebuffer[range]))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn copy_dirty_areas_from(&mut self, other: &Self) {
+        for area in other.dirty.iter() {
+            let sx = area.top_left.x as usize;
+            let sy = area.top_left.y as usize;
+
+            for y in sy..sy + area.size.height as usize {
+                let range = self.row_range(sx, y, area.size.width as usize);
+                self.framebuffer[range.clone()].copy_from_slice(&other.framebuffer[range]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics_core::prelude::{Point, Size};
+
+    use super::*;
+    use crate::{mock::take_dirty_area, models::ILI9486Rgb666};
+
+    #[test]
+    fn rgb666_is_stored_in_wire_format() -> Result<(), Error> {
+        let mut buffer = [0u8; 3 * 2 * 3];
+        let mut model = Rgb666Framebuffer::new(ILI9486Rgb666, &mut buffer);
+        model.update_options(&ModelOptions::with_sizes((3, 2), (3, 2)))?;
+
+        model.write_pixel(1, 1, Rgb666::new(0x3F, 0x01, 0x20))?;
+
+        assert_eq!(model.framebuffer[12..15], [0xFC, 0x04, 0x80]);
+        assert_eq!(
+            take_dirty_area(&mut model),
+            Some(Rectangle::new(Point::zero(), Size::new(3, 2)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rgb888_is_stored_unchanged() -> Result<(), Error> {
+        let mut buffer = [0u8; 3 * 2 * 3];
+        let mut model = Rgb888Framebuffer::new(ILI9486Rgb666, &mut buffer);
+        model.update_options(&ModelOptions::with_sizes((3, 2), (3, 2)))?;
+
+        model.clear(Rgb888::new(0x12, 0x34, 0x56))?;
+
+        assert_eq!(model.framebuffer[15..18], [0x12, 0x34, 0x56]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn framebuffer_too_small_is_rejected() {
+        let mut buffer = [0u8; 3 * 2 * 3 - 1];
+        let mut model = Rgb666Framebuffer::new(ILI9486Rgb666, &mut buffer);
+
+        let options = ModelOptions::with_sizes((3, 2), (3, 2));
+        assert!(model.update_options(&options).is_err());
+    }
+}
@@ -1,108 +1,97 @@
-use display_interface::{DataFormat, WriteOnlyDataCommand};
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat};
 use embedded_graphics_core::{pixelcolor::Rgb565, prelude::IntoStorage};
-use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
 
 use crate::{
     dcs::{
-        BitsPerPixel, ExitSleepMode, PixelFormat, SetAddressMode, SetDisplayOn, SetInvertMode,
-        SetPixelFormat, SoftReset, WriteMemoryStart,
+        AsyncDcs, BitsPerPixel, ExitSleepMode, PixelFormat, SetAddressMode, SetDisplayOn,
+        SetInvertMode, SetPixelFormat, SoftReset, WriteMemoryStart,
     },
     error::InitError,
-    Builder, ColorInversion, Error, ModelOptions,
+    AsyncBuilder, Error, ModelOptions,
 };
 
-use super::{Dcs, Model};
+use super::{AsyncModel, GammaControl, Model};
 
-/// ST7735s display in Rgb565 color mode.
-pub struct ST7735s;
+use super::ST7735s;
 
-impl Model for ST7735s {
+impl AsyncModel for ST7735s {
     type ColorFormat = Rgb565;
+    // SLPOUT needs 120 ms before the next command can be sent
+    const SLEEP_DELAY_US: u32 = 120_000;
 
-    fn init<RST, DELAY, DI>(
+    async fn init<RST, DELAY, DI>(
         &mut self,
-        dcs: &mut Dcs<DI>,
+        dcs: &mut AsyncDcs<DI>,
         delay: &mut DELAY,
         options: &ModelOptions,
         rst: &mut Option<RST>,
     ) -> Result<SetAddressMode, InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
-        DI: WriteOnlyDataCommand,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
     {
         let madctl = SetAddressMode::from(options);
 
         match rst {
-            Some(ref mut rst) => self.hard_reset(rst, delay)?,
-            None => dcs.write_command(SoftReset)?,
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
         }
-        delay.delay_us(200_000);
-
-        dcs.write_command(ExitSleepMode)?; // turn off sleep
-        delay.delay_us(120_000);
-
-        dcs.write_command(SetInvertMode(options.invert_colors))?; // set color inversion
-        dcs.write_raw(0xB1, &[0x05, 0x3A, 0x3A])?; // set frame rate
-        dcs.write_raw(0xB2, &[0x05, 0x3A, 0x3A])?; // set frame rate
-        dcs.write_raw(0xB3, &[0x05, 0x3A, 0x3A, 0x05, 0x3A, 0x3A])?; // set frame rate
-        dcs.write_raw(0xB4, &[0b0000_0011])?; // set inversion control
-        dcs.write_raw(0xC0, &[0x62, 0x02, 0x04])?; // set power control 1
-        dcs.write_raw(0xC1, &[0xC0])?; // set power control 2
-        dcs.write_raw(0xC2, &[0x0D, 0x00])?; // set power control 3
-        dcs.write_raw(0xC3, &[0x8D, 0x6A])?; // set power control 4
-        dcs.write_raw(0xC4, &[0x8D, 0xEE])?; // set power control 5
-        dcs.write_raw(0xC5, &[0x0E])?; // set VCOM control 1
-        dcs.write_raw(
-            0xE0,
-            &[
-                0x10, 0x0E, 0x02, 0x03, 0x0E, 0x07, 0x02, 0x07, 0x0A, 0x12, 0x27, 0x37, 0x00, 0x0D,
-                0x0E, 0x10,
-            ],
-        )?; // set GAMMA +Polarity characteristics
-        dcs.write_raw(
-            0xE1,
-            &[
-                0x10, 0x0E, 0x03, 0x03, 0x0F, 0x06, 0x02, 0x08, 0x0A, 0x13, 0x26, 0x36, 0x00, 0x0D,
-                0x0E, 0x10,
-            ],
-        )?; // set GAMMA -Polarity characteristics
+        delay.delay_us(200_000).await;
+
+        dcs.write_command(ExitSleepMode).await?; // turn off sleep
+        delay.delay_us(120_000).await;
+
+        dcs.write_command(SetInvertMode(options.invert_colors()))
+            .await?; // set color inversion
+        dcs.write_raw(0xB1, &[0x05, 0x3A, 0x3A]).await?; // set frame rate
+        dcs.write_raw(0xB2, &[0x05, 0x3A, 0x3A]).await?; // set frame rate
+        dcs.write_raw(0xB3, &[0x05, 0x3A, 0x3A, 0x05, 0x3A, 0x3A])
+            .await?; // set frame rate
+        dcs.write_raw(0xB4, &[0b0000_0011]).await?; // set inversion control
+        dcs.write_raw(0xC0, &[0x62, 0x02, 0x04]).await?; // set power control 1
+        dcs.write_raw(0xC1, &[0xC0]).await?; // set power control 2
+        dcs.write_raw(0xC2, &[0x0D, 0x00]).await?; // set power control 3
+        dcs.write_raw(0xC3, &[0x8D, 0x6A]).await?; // set power control 4
+        dcs.write_raw(0xC4, &[0x8D, 0xEE]).await?; // set power control 5
+        dcs.write_raw(0xC5, &[0x0E]).await?; // set VCOM control 1
+        dcs.write_raw(0xE0, &self.gamma().positive).await?; // set GAMMA +Polarity characteristics
+        dcs.write_raw(0xE1, &self.gamma().negative).await?; // set GAMMA -Polarity characteristics
 
         let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
-        dcs.write_command(SetPixelFormat::new(pf))?; // set interface pixel format, 16bit pixel into frame memory
+        dcs.write_command(SetPixelFormat::new(pf)).await?; // set interface pixel format, 16bit pixel into frame memory
 
-        dcs.write_command(madctl)?; // set memory data access control, Top -> Bottom, RGB, Left -> Right
-        dcs.write_command(SetDisplayOn)?; // turn on display
+        dcs.write_command(madctl).await?; // set memory data access control, Top -> Bottom, RGB, Left -> Right
+        dcs.write_command(SetDisplayOn).await?; // turn on display
 
         Ok(madctl)
     }
 
-    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
     where
-        DI: WriteOnlyDataCommand,
+        DI: AsyncWriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>,
     {
-        dcs.write_command(WriteMemoryStart)?;
+        dcs.write_command(WriteMemoryStart).await?;
         let mut iter = colors.into_iter().map(|c| c.into_storage());
 
         let buf = DataFormat::U16BEIter(&mut iter);
-        dcs.di.send_data(buf)?;
+        dcs.di.send_data(buf).await?;
         Ok(())
     }
 
     fn default_options() -> ModelOptions {
-        let mut options = ModelOptions::with_sizes((80, 160), (132, 162));
-        options.set_invert_colors(ColorInversion::Inverted);
-
-        options
+        <Self as Model>::default_options()
     }
 }
 
-// simplified constructor on Display
+// simplified constructor for AsyncDisplay
 
-impl<DI> Builder<DI, ST7735s>
+impl<DI> AsyncBuilder<DI, ST7735s>
 where
-    DI: WriteOnlyDataCommand,
+    DI: AsyncWriteOnlyDataCommand,
 {
     /// Creates a new display builder for ST7735s displays in Rgb565 color mode.
     ///
@@ -110,9 +99,64 @@ where
     ///
     /// # Arguments
     ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
     ///
     pub fn st7735s(di: DI) -> Self {
-        Self::with_model(di, ST7735s)
+        Self::with_model(di, ST7735s::default())
+    }
+
+    /// Creates a new display builder for the green tab variant of a ST7735s display in Rgb565
+    /// color mode.
+    ///
+    /// The green tab variant uses a display size of 128x160 inside a 132x162 framebuffer and BGR
+    /// subpixel order.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s_green_tab(di: DI) -> Self {
+        Self::new(di, ST7735s::default(), ST7735s::green_tab_options())
+    }
+
+    /// Creates a new display builder for the red tab variant of a ST7735s display in Rgb565
+    /// color mode.
+    ///
+    /// The red tab variant uses a display and framebuffer size of 128x160 and BGR subpixel order.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s_red_tab(di: DI) -> Self {
+        Self::new(di, ST7735s::default(), ST7735s::red_tab_options())
+    }
+
+    /// Creates a new display builder for the black tab variant of a ST7735s display in Rgb565
+    /// color mode.
+    ///
+    /// The black tab variant uses a display and framebuffer size of 128x160 and RGB subpixel
+    /// order.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s_black_tab(di: DI) -> Self {
+        Self::new(di, ST7735s::default(), ST7735s::black_tab_options())
+    }
+
+    /// Creates a new display builder for the 0.96" 80x160 mini variant of a ST7735s display in
+    /// Rgb565 color mode.
+    ///
+    /// The mini variant uses a display size of 80x160 inside a 132x162 framebuffer, BGR subpixel
+    /// order and inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s_mini(di: DI) -> Self {
+        Self::new(di, ST7735s::default(), ST7735s::mini_options())
     }
 }
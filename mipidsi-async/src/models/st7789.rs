@@ -1,157 +1,417 @@
-use display_interface::{DataFormat, WriteOnlyDataCommand};
-use embedded_graphics_core::{pixelcolor::Rgb565, prelude::IntoStorage};
-use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat};
+use embedded_graphics_core::{pixelcolor::Rgb565, prelude::IntoStorage, primitives::Rectangle};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
 
 use crate::{
     dcs::{
-        BitsPerPixel, Dcs, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
+        AsyncDcs, BitsPerPixel, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
         SetDisplayOn, SetInvertMode, SetPixelFormat, SetScrollArea, SoftReset, WriteMemoryStart,
     },
     error::InitError,
-    ColorInversion, Error, ModelOptions,
+    Error, ModelOptions,
 };
 
-use super::Model;
+use super::{
+    dirty_areas::DirtyAreas, AsyncFramebufferModel, AsyncModel, BrightnessControl, Model, ST7789,
+};
 
 /// Module containing all ST7789 variants.
 mod variants;
 
 /// ST7789 display in Rgb565 color mode.
-///
+/// With framebuffer on the MCU. Data only get's sent to the display with a call to [crate::AsyncDisplay::flush].
 /// Interfaces implemented by the [display-interface](https://crates.io/crates/display-interface) are supported.
-pub struct ST7789;
-pub struct ST7789Framebuf;
+///
+/// The framebuffer needs to hold at least `width * height` pixels of the configured display size.
+pub struct ST7789Framebuffer<'framebuffer> {
+    framebuffer: &'framebuffer mut [u16],
+    // Display size (w, h) in the current orientation
+    size: (u16, u16),
+    // Areas modified since the last flush
+    dirty: DirtyAreas,
+}
+
+impl<'framebuffer> ST7789Framebuffer<'framebuffer> {
+    /// Creates a new framebuffer model backed by the given `framebuffer`.
+    pub fn new(framebuffer: &'framebuffer mut [u16]) -> Self {
+        Self {
+            framebuffer,
+            size: (0, 0),
+            dirty: DirtyAreas::default(),
+        }
+    }
 
-impl Model for ST7789 {
+    // Number of framebuffer pixels used by the current display size
+    fn pixel_count(&self) -> usize {
+        usize::from(self.size.0) * usize::from(self.size.1)
+    }
+
+    // Marks the whole display as dirty
+    fn mark_all_dirty(&mut self) {
+        let (width, height) = self.size;
+        if width > 0 && height > 0 {
+            self.dirty.clear();
+            self.dirty.add(0, 0, width - 1, height - 1);
+        }
+    }
+}
+
+impl AsyncModel for ST7789 {
     type ColorFormat = Rgb565;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
 
-    fn init<RST, DELAY, DI>(
+    async fn init<RST, DELAY, DI>(
         &mut self,
-        dcs: &mut Dcs<DI>,
+        dcs: &mut AsyncDcs<DI>,
         delay: &mut DELAY,
         options: &ModelOptions,
         rst: &mut Option<RST>,
     ) -> Result<SetAddressMode, InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
-        DI: WriteOnlyDataCommand,
+        DELAY: AsyncDelayNs,
+        DI: AsyncWriteOnlyDataCommand,
     {
         let madctl = SetAddressMode::from(options);
 
         match rst {
-            Some(ref mut rst) => self.hard_reset(rst, delay)?,
-            None => dcs.write_command(SoftReset)?,
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
         }
-        delay.delay_us(150_000);
+        delay.delay_us(150_000).await;
 
-        dcs.write_command(ExitSleepMode)?;
-        delay.delay_us(10_000);
+        dcs.write_command(ExitSleepMode).await?;
+        delay.delay_us(10_000).await;
 
         // set hw scroll area based on framebuffer size
-        dcs.write_command(SetScrollArea::from(options))?;
-        dcs.write_command(madctl)?;
+        dcs.write_command(SetScrollArea::from(options)).await?;
+        dcs.write_command(madctl).await?;
 
-        dcs.write_command(SetInvertMode(options.invert_colors))?;
+        dcs.write_command(SetInvertMode(options.invert_colors()))
+            .await?;
 
         let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
-        dcs.write_command(SetPixelFormat::new(pf))?;
-        delay.delay_us(10_000);
-        dcs.write_command(EnterNormalMode)?;
-        delay.delay_us(10_000);
-        dcs.write_command(SetDisplayOn)?;
+        dcs.write_command(SetPixelFormat::new(pf)).await?;
+        delay.delay_us(10_000).await;
+        dcs.write_command(EnterNormalMode).await?;
+        delay.delay_us(10_000).await;
+        dcs.write_command(SetDisplayOn).await?;
 
         // DISPON requires some time otherwise we risk SPI data issues
-        delay.delay_us(120_000);
+        delay.delay_us(120_000).await;
 
         Ok(madctl)
     }
 
-    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
     where
-        DI: WriteOnlyDataCommand,
+        DI: AsyncWriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>,
     {
-        dcs.write_command(WriteMemoryStart)?;
+        dcs.write_command(WriteMemoryStart).await?;
 
         let mut iter = colors.into_iter().map(Rgb565::into_storage);
 
         let buf = DataFormat::U16BEIter(&mut iter);
-        dcs.di.send_data(buf)?;
+        dcs.di.send_data(buf).await?;
         Ok(())
     }
 
-    fn default_options() -> crate::ModelOptions {
-        let mut options = ModelOptions::with_sizes((240, 320), (240, 320));
-        options.set_invert_colors(ColorInversion::Normal);
-
-        options
+    fn default_options() -> ModelOptions {
+        <Self as Model>::default_options()
     }
 }
 
-impl AsyncModel for ST7789Framebuf {
+impl<'framebuffer> AsyncModel for ST7789Framebuffer<'framebuffer> {
     type ColorFormat = Rgb565;
-    
-    fn init<RST, DELAY, DI>(
+    const SLEEP_DELAY_US: u32 = <ST7789 as AsyncModel>::SLEEP_DELAY_US;
+
+    async fn init<RST, DELAY, DI>(
         &mut self,
-        dcs: &mut Dcs<DI>,
+        dcs: &mut AsyncDcs<DI>,
         delay: &mut DELAY,
         options: &ModelOptions,
         rst: &mut Option<RST>,
     ) -> Result<SetAddressMode, InitError<RST::Error>>
     where
         RST: OutputPin,
-        DELAY: DelayUs<u32>,
+        DELAY: AsyncDelayNs,
         DI: AsyncWriteOnlyDataCommand,
     {
-        let madctl = SetAddressMode::from(options);
+        AsyncModel::init(&mut ST7789, dcs, delay, options, rst).await
+    }
 
-        match rst {
-            Some(ref mut rst) => self.hard_reset(rst, delay)?,
-            None => dcs.write_command(SoftReset)?,
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        AsyncModel::write_pixels(&mut ST7789, dcs, colors).await
+    }
+
+    fn default_options() -> ModelOptions {
+        <ST7789 as Model>::default_options()
+    }
+
+    fn update_options(&mut self, options: &ModelOptions) -> Result<(), Error> {
+        let (width, height) = options.display_size();
+        if usize::from(width) * usize::from(height) > self.framebuffer.len() {
+            return Err(Error::OutOfBoundsError);
         }
-        delay.delay_us(150_000);
 
-        dcs.write_command(ExitSleepMode)?;
-        delay.delay_us(10_000);
+        if self.size != (width, height) {
+            self.size = (width, height);
+            self.mark_all_dirty();
+        }
 
-        // set hw scroll area based on framebuffer size
-        dcs.write_command(SetScrollArea::from(options))?;
-        dcs.write_command(madctl)?;
+        Ok(())
+    }
 
-        dcs.write_command(SetInvertMode(options.invert_colors))?;
+    fn write_framebuffer<I>(&mut self, area: &Rectangle, colors: &mut I) -> Result<bool, Error>
+    where
+        I: Iterator<Item = Self::ColorFormat>,
+    {
+        self.write_area(area, colors)
+    }
+}
 
-        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
-        dcs.write_command(SetPixelFormat::new(pf))?;
-        delay.delay_us(10_000);
-        dcs.write_command(EnterNormalMode)?;
-        delay.delay_us(10_000);
-        dcs.write_command(SetDisplayOn)?;
+impl BrightnessControl for ST7789Framebuffer<'_> {}
 
-        // DISPON requires some time otherwise we risk SPI data issues
-        delay.delay_us(120_000);
+impl<'framebuffer> AsyncFramebufferModel for ST7789Framebuffer<'framebuffer> {
+    fn clear(&mut self, color: Self::ColorFormat) -> Result<(), Error> {
+        let pixel_count = self.pixel_count();
+        self.framebuffer[..pixel_count].fill(color.into_storage());
+        self.mark_all_dirty();
 
-        Ok(madctl)
+        Ok(())
     }
 
-    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    fn write_pixel(&mut self, x: u16, y: u16, colors: Self::ColorFormat) -> Result<(), Error> {
+        let (width, height) = self.size;
+        if x >= width || y >= height {
+            return Err(Error::OutOfBoundsError);
+        }
+
+        let index = usize::from(x) + usize::from(y) * usize::from(width);
+        self.framebuffer[index] = colors.into_storage();
+        self.dirty.add_pixel(x, y);
+
+        Ok(())
+    }
+
+    fn dirty_area(&self) -> Option<Rectangle> {
+        self.dirty.peek()
+    }
+
+    fn clear_dirty_area(&mut self, area: &Rectangle) {
+        self.dirty.remove(area);
+    }
+
+    async fn flush<DI>(&mut self, dcs: &mut AsyncDcs<DI>, area: &Rectangle) -> Result<(), Error>
     where
-        DI: WriteOnlyDataCommand,
-        I: IntoIterator<Item = Self::ColorFormat>,
+        DI: AsyncWriteOnlyDataCommand,
     {
-        dcs.write_command(WriteMemoryStart)?;
+        let width = usize::from(self.size.0);
+        let sx = area.top_left.x as usize;
+        let sy = area.top_left.y as usize;
+        let area_width = area.size.width as usize;
+        let area_height = area.size.height as usize;
 
-        let mut iter = colors.into_iter().map(Rgb565::into_storage);
+        dcs.write_command(WriteMemoryStart).await?;
+
+        // the pixels are copied, `DataFormat::U16BE` allows the interface to byte swap the
+        // framebuffer in place, which would corrupt it for the next flush
+        if area_width == width {
+            // full rows are contiguous in the framebuffer
+            let start = sy * width;
+            let end = start + area_height * width;
+            let mut pixels = self.framebuffer[start..end].iter().copied();
+            dcs.di.send_data(DataFormat::U16BEIter(&mut pixels)).await?;
+        } else {
+            for y in sy..sy + area_height {
+                let start = y * width + sx;
+                let end = start + area_width;
+                let mut pixels = self.framebuffer[start..end].iter().copied();
+                dcs.di.send_data(DataFormat::U16BEIter(&mut pixels)).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn copy_dirty_areas_from(&mut self, other: &Self) {
+        let width = usize::from(self.size.0);
+
+        for area in other.dirty.iter() {
+            let sx = area.top_left.x as usize;
+            let sy = area.top_left.y as usize;
+            let area_width = area.size.width as usize;
+
+            for y in sy..sy + area.size.height as usize {
+                let start = y * width + sx;
+                let end = start + area_width;
+                self.framebuffer[start..end].copy_from_slice(&other.framebuffer[start..end]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics_core::{
+        prelude::{DrawTarget, Point, RgbColor, Size},
+        Pixel,
+    };
+
+    use super::*;
+    use crate::{
+        mock::{block_on, take_dirty_area, MockDisplayInterface},
+        FramebufferTarget, Orientation,
+    };
+
+    #[test]
+    fn framebuffer_uses_display_size_from_options() -> Result<(), Error> {
+        let mut buffer = [0u16; 4 * 3];
+        let mut model = ST7789Framebuffer::new(&mut buffer);
+
+        let mut options = ModelOptions::with_sizes((3, 4), (3, 4));
+        options.set_orientation(Orientation::Landscape(false));
+        model.update_options(&options)?;
+
+        model.write_pixel(3, 1, Rgb565::WHITE)?;
+
+        assert_eq!(model.framebuffer[7], Rgb565::WHITE.into_storage());
+
+        Ok(())
+    }
+
+    #[test]
+    fn framebuffer_tracks_dirty_areas() -> Result<(), Error> {
+        let mut buffer = [0u16; 4 * 3];
+        let mut model = ST7789Framebuffer::new(&mut buffer);
+
+        let options = ModelOptions::with_sizes((3, 4), (3, 4));
+        model.update_options(&options)?;
+        assert_eq!(
+            take_dirty_area(&mut model),
+            Some(Rectangle::new(Point::zero(), Size::new(3, 4)))
+        );
+        assert_eq!(take_dirty_area(&mut model), None);
+
+        model.write_pixel(1, 2, Rgb565::RED)?;
+        assert_eq!(
+            take_dirty_area(&mut model),
+            Some(Rectangle::new(Point::new(1, 2), Size::new(1, 1)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn flush_leaves_framebuffer_unchanged() -> Result<(), Error> {
+        let mut buffer = [0u16; 4 * 3];
+        let mut model = ST7789Framebuffer::new(&mut buffer);
+        model.update_options(&ModelOptions::with_sizes((3, 4), (3, 4)))?;
+        model.clear(Rgb565::RED)?;
+
+        let mut dcs = AsyncDcs::write_only(MockDisplayInterface::new());
+        let area = Rectangle::new(Point::new(1, 1), Size::new(2, 2));
+        for _ in 0..2 {
+            dcs.di.clear();
+            block_on(model.flush(&mut dcs, &area))?;
+            assert_eq!(
+                dcs.di.log(),
+                &[0x2C, 0xF8, 0x00, 0xF8, 0x00, 0xF8, 0x00, 0xF8, 0x00]
+            );
+        }
+        assert!(model
+            .framebuffer
+            .iter()
+            .all(|pixel| *pixel == Rgb565::RED.into_storage()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_area_wraps_around() -> Result<(), Error> {
+        let mut buffer = [0u16; 4 * 4];
+        let mut model = ST7789Framebuffer::new(&mut buffer);
+        model.update_options(&ModelOptions::with_sizes((4, 4), (4, 4)))?;
+        while take_dirty_area(&mut model).is_some() {}
+
+        let area = Rectangle::new(Point::new(1, 1), Size::new(2, 1));
+        let mut colors = [Rgb565::RED, Rgb565::GREEN, Rgb565::BLUE].iter().copied();
+        assert!(model.write_framebuffer(&area, &mut colors)?);
+        assert_eq!(take_dirty_area(&mut model), Some(area));
+
+        assert_eq!(buffer[5], Rgb565::BLUE.into_storage());
+        assert_eq!(buffer[6], Rgb565::GREEN.into_storage());
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_area_skips_pixels_outside_of_framebuffer() -> Result<(), Error> {
+        let mut buffer = [0u16; 4 * 4];
+        let mut model = ST7789Framebuffer::new(&mut buffer);
+        model.update_options(&ModelOptions::with_sizes((4, 4), (4, 4)))?;
+
+        let area = Rectangle::new(Point::new(3, 3), Size::new(2, 1));
+        let mut colors = [Rgb565::RED, Rgb565::GREEN].iter().copied();
+        assert!(model.write_framebuffer(&area, &mut colors)?);
+
+        assert_eq!(buffer[15], Rgb565::RED.into_storage());
 
-        let buf = DataFormat::U16BEIter(&mut iter);
-        dcs.di.send_data(buf)?;
         Ok(())
     }
 
-    fn default_options() -> crate::ModelOptions {
-        let mut options = ModelOptions::with_sizes((240, 320), (240, 320));
-        options.set_invert_colors(ColorInversion::Normal);
+    #[test]
+    fn framebuffer_too_small_is_rejected() {
+        let mut buffer = [0u16; 10];
+        let mut model = ST7789Framebuffer::new(&mut buffer);
 
-        options
+        let options = ModelOptions::with_sizes((3, 4), (3, 4));
+        assert!(model.update_options(&options).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn framebuffer_rejects_out_of_bounds_pixel() {
+        let mut buffer = [0u16; 4 * 3];
+        let mut model = ST7789Framebuffer::new(&mut buffer);
+        model
+            .update_options(&ModelOptions::with_sizes((3, 4), (3, 4)))
+            .unwrap();
+
+        assert!(matches!(
+            model.write_pixel(3, 0, Rgb565::RED),
+            Err(Error::OutOfBoundsError)
+        ));
+    }
+
+    #[test]
+    fn framebuffer_target_clips_draws() -> Result<(), Error> {
+        let mut buffer = [0u16; 4 * 3];
+        let mut model = ST7789Framebuffer::new(&mut buffer);
+        model.update_options(&ModelOptions::with_sizes((3, 4), (3, 4)))?;
+
+        let mut target = FramebufferTarget::new(&mut model, 3, 4);
+        target.draw_iter([
+            Pixel(Point::new(-1, 0), Rgb565::RED),
+            Pixel(Point::new(0, 4), Rgb565::RED),
+            Pixel(Point::new(2, 3), Rgb565::GREEN),
+        ])?;
+        target.fill_solid(
+            &Rectangle::new(Point::new(-2, -2), Size::new(3, 3)),
+            Rgb565::BLUE,
+        )?;
+
+        let mut expected = [0u16; 4 * 3];
+        expected[0] = Rgb565::BLUE.into_storage();
+        expected[11] = Rgb565::GREEN.into_storage();
+        assert_eq!(model.framebuffer, expected);
+
+        Ok(())
+    }
+}
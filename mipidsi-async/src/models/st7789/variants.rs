@@ -1,12 +1,12 @@
-use display_interface::WriteOnlyDataCommand;
+use display_interface::AsyncWriteOnlyDataCommand;
 
-use crate::{Builder, ColorInversion, ModelOptions, Orientation};
+use crate::{builder::AsyncBuilder, models::DoubleBuffer};
 
-use super::ST7789;
+use super::{ST7789Framebuffer, ST7789};
 
-impl<DI> Builder<DI, ST7789>
+impl<DI> AsyncBuilder<DI, ST7789>
 where
-    DI: WriteOnlyDataCommand,
+    DI: AsyncWriteOnlyDataCommand,
 {
     /// Creates a new display builder for a ST7789 display in Rgb565 color mode.
     ///
@@ -14,7 +14,7 @@ where
     ///
     /// # Arguments
     ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
     ///
     pub fn st7789(di: DI) -> Self {
         Self::with_model(di, ST7789)
@@ -27,27 +27,124 @@ where
     ///
     /// # Arguments
     ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
     ///
     pub fn st7789_pico1(di: DI) -> Self {
-        let mut options = ModelOptions::with_all((135, 240), (135, 240), pico1_offset);
-        options.set_invert_colors(ColorInversion::Inverted);
+        Self::new(di, ST7789, ST7789::pico1_options())
+    }
+
+    /// Creates a new display builder for a 1.3" 240x240 ST7789 display in Rgb565 color mode.
+    ///
+    /// The 240x240 variant uses a display size of 240x240 inside a 240x320 framebuffer and
+    /// inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7789_240x240(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::options_240x240())
+    }
+
+    /// Creates a new display builder for a 1.69" 240x280 ST7789 display in Rgb565 color mode.
+    ///
+    /// The 240x280 variant uses a display size of 240x280 inside a 240x320 framebuffer and
+    /// inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7789_240x280(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::options_240x280())
+    }
+
+    /// Creates a new display builder for a 1.47" 172x320 ST7789 display in Rgb565 color mode.
+    ///
+    /// The 172x320 variant uses a display size of 172x320 inside a 240x320 framebuffer and
+    /// inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7789_172x320(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::options_172x320())
+    }
+
+    /// Creates a new display builder for a 1.9" 170x320 ST7789 display in Rgb565 color mode.
+    ///
+    /// The 170x320 variant uses a display size of 170x320 inside a 240x320 framebuffer and
+    /// inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7789_170x320(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::options_170x320())
+    }
+}
+
+impl<'framebuffer, DI> AsyncBuilder<DI, ST7789Framebuffer<'framebuffer>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for a ST7789 display in Rgb565 color mode with a integrated framebuffer.
+    ///
+    /// The default framebuffer size and display size is 240x320 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `framebuffer` - the framebuffer to store the data, needs to hold at least `width * height` pixels of the
+    ///   display size. [embedded_graphics_core::draw_target::DrawTarget] operations only write to the framebuffer
+    ///   and a [crate::AsyncDisplay::flush] call is necessary to actually send the data.
+    pub fn st7789_framebuffer(di: DI, framebuffer: &'framebuffer mut [u16]) -> Self {
+        Self::with_model(di, ST7789Framebuffer::new(framebuffer))
+    }
 
-        // pico v1 is cropped to 135x240 size with an offset of (40, 53)
-        Self::new(di, ST7789, options)
+    /// Creates a new display builder for the pico1 variant of a ST7789 display in Rgb565 color
+    /// mode with a integrated framebuffer.
+    ///
+    /// The pico1 variant uses a display and framebuffer size of 135x240 and a clipping offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `framebuffer` - the framebuffer to store the data, needs to hold at least 135x240 pixels
+    pub fn st7789_pico1_framebuffer(di: DI, framebuffer: &'framebuffer mut [u16]) -> Self {
+        Self::new(
+            di,
+            ST7789Framebuffer::new(framebuffer),
+            ST7789::pico1_options(),
+        )
     }
 }
 
-// ST7789 pico1 variant with variable offset
-pub(crate) fn pico1_offset(options: &ModelOptions) -> (u16, u16) {
-    match options.orientation() {
-        Orientation::Portrait(false) => (52, 40),
-        Orientation::Portrait(true) => (53, 40),
-        Orientation::Landscape(false) => (40, 52),
-        Orientation::Landscape(true) => (40, 53),
-        Orientation::PortraitInverted(false) => (53, 40),
-        Orientation::PortraitInverted(true) => (52, 40),
-        Orientation::LandscapeInverted(false) => (40, 53),
-        Orientation::LandscapeInverted(true) => (40, 52),
+impl<'framebuffer, DI> AsyncBuilder<DI, DoubleBuffer<ST7789Framebuffer<'framebuffer>>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for a ST7789 display in Rgb565 color mode with two integrated
+    /// framebuffers.
+    ///
+    /// The default framebuffer size and display size is 240x320 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    /// * `front` - the framebuffer sent to the display by [crate::AsyncDisplay::flush]
+    /// * `back` - the framebuffer [embedded_graphics_core::draw_target::DrawTarget] operations write to,
+    ///   exchanged with `front` by [crate::AsyncDisplay::swap]
+    pub fn st7789_double_framebuffer(
+        di: DI,
+        front: &'framebuffer mut [u16],
+        back: &'framebuffer mut [u16],
+    ) -> Self {
+        Self::with_model(
+            di,
+            DoubleBuffer::new(ST7789Framebuffer::new(front), ST7789Framebuffer::new(back)),
+        )
     }
 }
@@ -0,0 +1,197 @@
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat};
+use embedded_graphics_core::{
+    pixelcolor::{Rgb565, Rgb666},
+    prelude::{IntoStorage, RgbColor},
+};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+
+use crate::{
+    dcs::{
+        AsyncDcs, BitsPerPixel, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
+        SetDisplayOn, SetInvertMode, SetPixelFormat, SoftReset, WriteMemoryStart,
+    },
+    error::InitError,
+    AsyncBuilder, Error, ModelOptions,
+};
+
+use super::{AsyncModel, GammaControl, Model, ST7796SGamma};
+
+use super::{ST7796SRgb565, ST7796SRgb666};
+
+impl AsyncModel for ST7796SRgb565 {
+    type ColorFormat = Rgb565;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
+
+    async fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut AsyncDcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
+        }
+        delay.delay_us(120_000).await;
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common_async(dcs, delay, options, pf, self.gamma()).await?)
+    }
+
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart).await?;
+        let mut iter = colors.into_iter().map(|c| c.into_storage());
+
+        let buf = DataFormat::U16BEIter(&mut iter);
+        dcs.di.send_data(buf).await
+    }
+
+    fn default_options() -> ModelOptions {
+        <Self as Model>::default_options()
+    }
+}
+
+impl AsyncModel for ST7796SRgb666 {
+    type ColorFormat = Rgb666;
+    // SLPIN needs 5 ms, waiting 120 ms also keeps the minimum time between SLPOUT and SLPIN
+    const SLEEP_DELAY_US: u32 = 120_000;
+
+    async fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut AsyncDcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: AsyncWriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => AsyncModel::hard_reset(self, rst, delay).await?,
+            None => dcs.write_command(SoftReset).await?,
+        }
+        delay.delay_us(120_000).await;
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common_async(dcs, delay, options, pf, self.gamma()).await?)
+    }
+
+    async fn write_pixels<DI, I>(&mut self, dcs: &mut AsyncDcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: AsyncWriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart).await?;
+        let mut iter = colors.into_iter().flat_map(|c| {
+            let red = c.r() << 2;
+            let green = c.g() << 2;
+            let blue = c.b() << 2;
+            [red, green, blue]
+        });
+
+        let buf = DataFormat::U8Iter(&mut iter);
+        dcs.di.send_data(buf).await
+    }
+
+    fn default_options() -> ModelOptions {
+        <Self as Model>::default_options()
+    }
+}
+
+// simplified constructor for AsyncDisplay
+
+impl<DI> AsyncBuilder<DI, ST7796SRgb565>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ST7796S display in Rgb565 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7796s_rgb565(di: DI) -> Self {
+        Self::with_model(di, ST7796SRgb565::default())
+    }
+}
+
+impl<DI> AsyncBuilder<DI, ST7796SRgb666>
+where
+    DI: AsyncWriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ST7796S display in Rgb666 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](AsyncWriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7796s_rgb666(di: DI) -> Self {
+        Self::with_model(di, ST7796SRgb666::default())
+    }
+}
+
+// common init for all color format models using an async interface
+async fn init_common_async<DELAY, DI>(
+    dcs: &mut AsyncDcs<DI>,
+    delay: &mut DELAY,
+    options: &ModelOptions,
+    pixel_format: PixelFormat,
+    gamma: &ST7796SGamma,
+) -> Result<SetAddressMode, Error>
+where
+    DELAY: DelayNs,
+    DI: AsyncWriteOnlyDataCommand,
+{
+    let madctl = SetAddressMode::from(options);
+    dcs.write_command(ExitSleepMode).await?; // turn off sleep
+    delay.delay_us(120_000).await;
+
+    dcs.write_raw(0xF0, &[0xC3]).await?; // enable command set part 1
+    dcs.write_raw(0xF0, &[0x96]).await?; // enable command set part 2
+
+    dcs.write_command(madctl).await?; // set memory data access control
+    dcs.write_command(SetPixelFormat::new(pixel_format)).await?; // pixel format
+    dcs.write_raw(0xB4, &[0x01]).await?; // 1-dot inversion control
+    dcs.write_raw(0xB6, &[0x80, 0x02, 0x3B]).await?; // DFC
+    dcs.write_raw(0xE8, &[0x40, 0x8A, 0x00, 0x00, 0x29, 0x19, 0xA5, 0x33])
+        .await?; // display output ctrl adjust
+    dcs.write_raw(0xC1, &[0x06]).await?; // power control 2
+    dcs.write_raw(0xC2, &[0xA7]).await?; // power control 3
+    dcs.write_raw(0xC5, &[0x18]).await?; // VCOM control
+    delay.delay_us(120_000).await;
+
+    dcs.write_raw(0xE0, &gamma.positive).await?; // positive gamma control
+    dcs.write_raw(0xE1, &gamma.negative).await?; // negative gamma control
+    delay.delay_us(120_000).await;
+
+    dcs.write_raw(0xF0, &[0x3C]).await?; // disable command set part 1
+    dcs.write_raw(0xF0, &[0x69]).await?; // disable command set part 2
+
+    dcs.write_command(SetInvertMode(options.invert_colors()))
+        .await?;
+    dcs.write_command(EnterNormalMode).await?; // turn to normal mode
+    dcs.write_command(SetDisplayOn).await?; // turn on display
+
+    // DISPON requires some time otherwise we risk SPI data issues
+    delay.delay_us(120_000).await;
+
+    Ok(madctl)
+}
@@ -0,0 +1,226 @@
+//! Strip buffer rendering for displays without a full framebuffer.
+
+use core::convert::Infallible;
+
+use embedded_graphics_core::{
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+    Pixel,
+};
+
+use crate::Error;
+
+/// [DrawTarget] covering a horizontal band of the display.
+///
+/// Passed to the render callback of [`Display::render_bands`](crate::Display::render_bands) and
+/// `AsyncDisplay::render_bands` in mipidsi-async. The target has the size of the
+/// whole display, drawing outside of the current band is discarded. Use [Band::area] to skip
+/// drawing items that don't intersect the band.
+///
+/// The band buffer is not cleared between bands, the render callback should redraw every pixel,
+/// e.g. by starting with [DrawTarget::clear].
+pub struct Band<'a, C> {
+    buffer: &'a mut [C],
+    // Display size (w, h) in the current orientation
+    size: (u16, u16),
+    // Area of the display covered by the buffer
+    area: Rectangle,
+}
+
+impl<'a, C> Band<'a, C>
+where
+    C: PixelColor,
+{
+    fn new(buffer: &'a mut [C], width: u16, height: u16, top: u16, rows: u16) -> Self {
+        Self {
+            buffer,
+            size: (width, height),
+            area: Rectangle::new(
+                Point::new(0, i32::from(top)),
+                Size::new(u32::from(width), u32::from(rows)),
+            ),
+        }
+    }
+
+    /// Returns the area of the display covered by this band.
+    pub fn area(&self) -> Rectangle {
+        self.area
+    }
+
+    // Returns the buffer index of a point inside the band
+    fn index(&self, point: Point) -> usize {
+        let x = point.x as usize;
+        let y = (point.y - self.area.top_left.y) as usize;
+
+        y * usize::from(self.size.0) + x
+    }
+}
+
+/// Splits the display into bands which fit into a band buffer.
+///
+/// Shared by the sync and async `render_bands` implementations.
+pub struct Bands {
+    // Display size (w, h) in the current orientation
+    size: (u16, u16),
+    // Number of rows per band
+    band_rows: u16,
+    // First row of the next band
+    top: u16,
+}
+
+impl Bands {
+    /// Creates the bands for a display of the given size and a band buffer of `buffer_len` pixels.
+    ///
+    /// Returns [Error::OutOfBoundsError] if the buffer can't hold a single row.
+    pub fn new(buffer_len: usize, width: u16, height: u16) -> Result<Self, Error> {
+        let rows = buffer_len
+            .checked_div(usize::from(width))
+            .unwrap_or(0)
+            .min(usize::from(height));
+
+        if rows == 0 {
+            // the buffer needs to hold at least one row
+            return Err(Error::OutOfBoundsError);
+        }
+
+        Ok(Self {
+            size: (width, height),
+            band_rows: rows as u16,
+            top: 0,
+        })
+    }
+
+    /// Renders the next band into `buffer`.
+    ///
+    /// Returns the first and last row of the band and the rendered pixels, or `None` once
+    /// the whole display was rendered.
+    pub fn render_next<'a, C, F>(
+        &mut self,
+        buffer: &'a mut [C],
+        render: &mut F,
+    ) -> Option<(u16, u16, &'a [C])>
+    where
+        C: PixelColor,
+        F: FnMut(&mut Band<'_, C>),
+    {
+        let (width, height) = self.size;
+        if self.top >= height {
+            return None;
+        }
+
+        let top = self.top;
+        let rows = self.band_rows.min(height - top);
+        let pixels = &mut buffer[..usize::from(width) * usize::from(rows)];
+        render(&mut Band::new(pixels, width, height, top, rows));
+        self.top += rows;
+
+        Some((top, top + rows - 1, pixels))
+    }
+}
+
+impl<C> DrawTarget for Band<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if self.area.contains(point) {
+                let index = self.index(point);
+                self.buffer[index] = color;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.area);
+
+        if let Some(bottom_right) = area.bottom_right() {
+            let width = area.size.width as usize;
+
+            for y in area.top_left.y..=bottom_right.y {
+                let start = self.index(Point::new(area.top_left.x, y));
+                self.buffer[start..start + width].fill(color);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.buffer.fill(color);
+
+        Ok(())
+    }
+}
+
+impl<C> OriginDimensions for Band<'_, C> {
+    fn size(&self) -> Size {
+        Size::new(u32::from(self.size.0), u32::from(self.size.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics_core::pixelcolor::{Rgb565, RgbColor};
+
+    use super::*;
+
+    #[test]
+    fn draws_outside_of_band_are_discarded() {
+        let mut buffer = [Rgb565::BLACK; 4 * 2];
+        let mut band = Band::new(&mut buffer, 4, 6, 2, 2);
+
+        band.draw_iter([
+            Pixel(Point::new(1, 1), Rgb565::RED),
+            Pixel(Point::new(1, 2), Rgb565::GREEN),
+            Pixel(Point::new(3, 4), Rgb565::RED),
+        ])
+        .unwrap();
+        band.fill_solid(
+            &Rectangle::new(Point::new(2, 0), Size::new(2, 4)),
+            Rgb565::BLUE,
+        )
+        .unwrap();
+
+        assert_eq!(
+            buffer,
+            [
+                Rgb565::BLACK,
+                Rgb565::GREEN,
+                Rgb565::BLUE,
+                Rgb565::BLUE,
+                Rgb565::BLACK,
+                Rgb565::BLACK,
+                Rgb565::BLUE,
+                Rgb565::BLUE,
+            ]
+        );
+    }
+
+    #[test]
+    fn bands_cover_the_display() {
+        let mut buffer = [Rgb565::BLACK; 4 * 2];
+        let mut bands = Bands::new(buffer.len(), 4, 5).unwrap();
+        let mut render = |band: &mut Band<'_, Rgb565>| band.clear(Rgb565::RED).unwrap();
+
+        assert_eq!(bands.render_next(&mut buffer, &mut render).unwrap().0, 0);
+        let (top, bottom, pixels) = bands.render_next(&mut buffer, &mut render).unwrap();
+        assert_eq!((top, bottom, pixels.len()), (2, 3, 8));
+        let (top, bottom, pixels) = bands.render_next(&mut buffer, &mut render).unwrap();
+        assert_eq!((top, bottom, pixels.len()), (4, 4, 4));
+        assert!(bands.render_next(&mut buffer, &mut render).is_none());
+    }
+
+    #[test]
+    fn buffer_smaller_than_a_row_is_rejected() {
+        assert!(Bands::new(3, 4, 5).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 //! MIPI DCS commands.
 
-use display_interface::{DataFormat, WriteOnlyDataCommand, AsyncWriteOnlyDataCommand};
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
 
 use crate::Error;
 
@@ -23,6 +23,30 @@ mod set_tearing_effect;
 pub use set_tearing_effect::*;
 mod set_invert_mode;
 pub use set_invert_mode::*;
+mod set_partial_rows;
+pub use set_partial_rows::*;
+mod set_partial_columns;
+pub use set_partial_columns::*;
+mod write_display_brightness;
+pub use write_display_brightness::*;
+mod write_ctrl_display;
+pub use write_ctrl_display::*;
+mod write_cabc;
+pub use write_cabc::*;
+mod set_gamma_curve;
+pub use set_gamma_curve::*;
+mod set_tear_scanline;
+pub use set_tear_scanline::*;
+mod get_scanline;
+pub use get_scanline::*;
+mod read_display_id;
+pub use read_display_id::*;
+mod read_display_status;
+pub use read_display_status::*;
+mod read_power_mode;
+pub use read_power_mode::*;
+mod read_address_mode;
+pub use read_address_mode::*;
 
 /// Common trait for DCS commands.
 ///
@@ -35,6 +59,42 @@ pub trait DcsCommand {
     fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error>;
 }
 
+/// Common trait for DCS read commands.
+///
+/// The methods in this trait are used to send a read command and to convert the returned bytes
+/// into the response type.
+pub trait DcsReadCommand {
+    /// Response returned by the display.
+    type Response;
+
+    /// Returns the instruction code.
+    fn instruction(&self) -> u8;
+
+    /// Returns the number of response bytes, excluding any dummy bytes.
+    fn response_len(&self) -> usize;
+
+    /// Converts the response bytes into the response type.
+    ///
+    /// The `buffer` contains exactly [`response_len`](Self::response_len) bytes.
+    fn parse_response(&self, buffer: &[u8]) -> Self::Response;
+}
+
+/// Display interface which can also read data from the display.
+///
+/// [`display_interface`] only provides write access to the display, this trait adds the read
+/// direction which is required by [`Dcs::read_command`]. It should be implemented for interfaces
+/// that have a readable data line, e.g. an SPI interface with MISO or a bidirectional SDA line or
+/// a parallel interface with an RD pin.
+pub trait ReadWriteDataCommand: WriteOnlyDataCommand {
+    /// Reads data from the display into `buffer`.
+    ///
+    /// This is called after the read instruction was sent using
+    /// [`send_commands`](WriteOnlyDataCommand::send_commands). Dummy clock cycles or dummy bytes,
+    /// which some controllers send before the actual response, must be skipped by the
+    /// implementation.
+    fn read_data(&mut self, buffer: &mut [u8]) -> Result<(), DisplayError>;
+}
+
 /// Wrapper around [`WriteOnlyDataCommand`] with support for writing DCS commands.
 ///
 /// Commands which are part of the manufacturer independent user command set can be sent to the
@@ -44,17 +104,14 @@ pub trait DcsCommand {
 /// All other commands, which do not have an associated type in this module, can be sent using
 /// the [`write_raw`](Self::write_raw) method. The underlying display interface is also accessible
 /// using the public [`di`](Self::di) field.
+///
+/// If the display interface implements [`ReadWriteDataCommand`] the display registers can be read
+/// using the [`read_command`](Self::read_command) and [`read_raw`](Self::read_raw) methods.
 pub struct Dcs<DI> {
     /// Display interface instance.
     pub di: DI,
 }
 
-/// Same as [`Dcs`] but with async
-pub struct AsyncDcs<DI> {
-    /// Display interface instance.
-    pub di: DI,
-}
-
 impl<DI> Dcs<DI>
 where
     DI: WriteOnlyDataCommand,
@@ -95,43 +152,30 @@ where
     }
 }
 
-impl<DI> AsyncDcs<DI>
+impl<DI> Dcs<DI>
 where
-    DI: AsyncWriteOnlyDataCommand,
+    DI: ReadWriteDataCommand,
 {
-    /// Creates a new [Dcs] instance from a display interface.
-    pub fn write_only(di: DI) -> Self {
-        Self { di }
-    }
-
-    /// Releases the display interface.
-    pub fn release(self) -> DI {
-        self.di
-    }
+    /// Sends a DCS read command to the display interface and returns the parsed response.
+    pub fn read_command<C: DcsReadCommand>(&mut self, command: C) -> Result<C::Response, Error> {
+        let mut response_bytes: [u8; 16] = [0; 16];
+        let response_bytes = response_bytes
+            .get_mut(..command.response_len())
+            .ok_or(Error::OutOfBoundsError)?;
+        self.read_raw(command.instruction(), response_bytes)?;
 
-    /// Sends a DCS command to the display interface.
-    pub async fn write_command(&mut self, command: impl DcsCommand) -> Result<(), Error> {
-        let mut param_bytes: [u8; 16] = [0; 16];
-        let n = command.fill_params_buf(&mut param_bytes)?;
-        self.write_raw(command.instruction(), &param_bytes[..n]).await
+        Ok(command.parse_response(response_bytes))
     }
 
-    /// Sends a raw command with the given `instruction` to the display interface.
+    /// Sends a raw read command with the given `instruction` and reads the response into
+    /// `buffer`.
     ///
-    /// The `param_bytes` slice can contain the instruction parameters, which are sent as data after
-    /// the instruction code was sent. If no parameters are required an empty slice can be passed to
-    /// this method.
-    ///
-    /// This method is intended to be used for sending commands which are not part of the MIPI DCS
-    /// user command set. Use [`write_command`](Self::write_command) for commands in the user
+    /// This method is intended to be used for reading registers which are not part of the MIPI
+    /// DCS user command set. Use [`read_command`](Self::read_command) for commands in the user
     /// command set.
-    pub async fn write_raw(&mut self, instruction: u8, param_bytes: &[u8]) -> Result<(), Error> {
-        self.di.send_commands(DataFormat::U8(&[instruction])).await?;
-
-        if !param_bytes.is_empty() {
-            self.di.send_data(DataFormat::U8(param_bytes)).await?; // TODO: empty guard?
-        }
-        Ok(())
+    pub fn read_raw(&mut self, instruction: u8, buffer: &mut [u8]) -> Result<(), Error> {
+        self.di.send_commands(DataFormat::U8(&[instruction]))?;
+        self.di.read_data(buffer)
     }
 }
 
@@ -199,3 +243,34 @@ dcs_basic_command!(
     WriteMemoryStart,
     0x2C
 );
+dcs_basic_command!(
+    /// Continue Framebuffer Memory Write
+    WriteMemoryContinue,
+    0x3C
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::mock::MockDisplayInterface;
+
+    use super::*;
+
+    #[test]
+    fn read_command_sends_instruction_and_parses_response() {
+        let di = MockDisplayInterface::with_response(0x04, &[0x85, 0x85, 0x52]);
+        let mut dcs = Dcs::write_only(di);
+
+        assert_eq!(
+            dcs.read_command(ReadDisplayId).unwrap(),
+            DisplayId {
+                manufacturer: 0x85,
+                version: 0x85,
+                module: 0x52,
+            }
+        );
+        assert_eq!(dcs.di.log(), &[0x04]);
+
+        // the mock only responds to the configured instruction
+        assert!(dcs.read_command(ReadPowerMode).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+//! Module for the GSCAN scanline instruction
+
+use super::DcsReadCommand;
+
+/// Get Scanline
+///
+/// The response is the scanline which is currently refreshed by the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetScanline;
+
+impl DcsReadCommand for GetScanline {
+    type Response = u16;
+
+    fn instruction(&self) -> u8 {
+        0x45
+    }
+
+    fn response_len(&self) -> usize {
+        2
+    }
+
+    fn parse_response(&self, buffer: &[u8]) -> Self::Response {
+        u16::from_be_bytes([buffer[0], buffer[1]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gscan_parses_response() {
+        assert_eq!(GetScanline.instruction(), 0x45);
+        assert_eq!(GetScanline.parse_response(&[0x01, 0x3F]), 0x13F);
+    }
+}
@@ -0,0 +1,46 @@
+//! Module for the RDDMADCTL instruction
+
+use super::{DcsReadCommand, SetAddressMode};
+
+/// Read Display MADCTL
+///
+/// The response is returned as a [SetAddressMode] value, which can be compared to the value
+/// that was written to the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadAddressMode;
+
+impl DcsReadCommand for ReadAddressMode {
+    type Response = SetAddressMode;
+
+    fn instruction(&self) -> u8 {
+        0x0B
+    }
+
+    fn response_len(&self) -> usize {
+        1
+    }
+
+    fn parse_response(&self, buffer: &[u8]) -> Self::Response {
+        SetAddressMode::from_bits(buffer[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColorOrder, Orientation, RefreshOrder};
+
+    use super::*;
+
+    #[test]
+    fn rddmadctl_parses_response() {
+        assert_eq!(ReadAddressMode.instruction(), 0x0B);
+        assert_eq!(
+            ReadAddressMode.parse_response(&[0b0110_1000]),
+            SetAddressMode::new(
+                ColorOrder::Bgr,
+                Orientation::Landscape(true),
+                RefreshOrder::default()
+            )
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Module for the RDDID instruction
+
+use super::DcsReadCommand;
+
+/// Read Display ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadDisplayId;
+
+/// Display identification returned by [ReadDisplayId]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayId {
+    /// Module manufacturer ID (ID1)
+    pub manufacturer: u8,
+    /// Module or driver version ID (ID2)
+    pub version: u8,
+    /// Module or driver ID (ID3)
+    pub module: u8,
+}
+
+impl DcsReadCommand for ReadDisplayId {
+    type Response = DisplayId;
+
+    fn instruction(&self) -> u8 {
+        0x04
+    }
+
+    fn response_len(&self) -> usize {
+        3
+    }
+
+    fn parse_response(&self, buffer: &[u8]) -> Self::Response {
+        DisplayId {
+            manufacturer: buffer[0],
+            version: buffer[1],
+            module: buffer[2],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rddid_parses_response() {
+        assert_eq!(ReadDisplayId.instruction(), 0x04);
+        assert_eq!(
+            ReadDisplayId.parse_response(&[0x85, 0x85, 0x52]),
+            DisplayId {
+                manufacturer: 0x85,
+                version: 0x85,
+                module: 0x52,
+            }
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Module for the RDDST instruction
+
+use crate::TearingEffect;
+
+use super::{DcsReadCommand, SetAddressMode};
+
+/// Read Display Status
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadDisplayStatus;
+
+/// Display status returned by [ReadDisplayStatus]
+///
+/// The four status bytes are stored in the order they were received, the first byte in the most
+/// significant bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayStatus(pub u32);
+
+impl DisplayStatus {
+    /// Returns `true` if the booster voltage is on.
+    pub const fn booster_on(&self) -> bool {
+        self.bit(31)
+    }
+
+    /// Returns the current memory access control settings.
+    pub const fn address_mode(&self) -> SetAddressMode {
+        // D30..D25 contain the MY, MX, MV, ML, RGB and MH bits of MADCTL
+        SetAddressMode::from_bits((self.0 >> 23) as u8 & 0b1111_1100)
+    }
+
+    /// Returns `true` if idle mode is on.
+    pub const fn idle_mode(&self) -> bool {
+        self.bit(19)
+    }
+
+    /// Returns `true` if partial mode is on.
+    pub const fn partial_mode(&self) -> bool {
+        self.bit(18)
+    }
+
+    /// Returns `true` if the display is out of sleep mode.
+    pub const fn sleep_out(&self) -> bool {
+        self.bit(17)
+    }
+
+    /// Returns `true` if normal mode is on.
+    pub const fn normal_mode(&self) -> bool {
+        self.bit(16)
+    }
+
+    /// Returns `true` if color inversion is on.
+    pub const fn inverted(&self) -> bool {
+        self.bit(13)
+    }
+
+    /// Returns `true` if the display is on.
+    pub const fn display_on(&self) -> bool {
+        self.bit(10)
+    }
+
+    /// Returns the current tearing effect output mode.
+    pub const fn tearing_effect(&self) -> TearingEffect {
+        match (self.bit(9), self.bit(5)) {
+            (false, _) => TearingEffect::Off,
+            (true, false) => TearingEffect::Vertical,
+            (true, true) => TearingEffect::HorizontalAndVertical,
+        }
+    }
+
+    const fn bit(&self, bit: u32) -> bool {
+        self.0 & (1 << bit) != 0
+    }
+}
+
+impl DcsReadCommand for ReadDisplayStatus {
+    type Response = DisplayStatus;
+
+    fn instruction(&self) -> u8 {
+        0x09
+    }
+
+    fn response_len(&self) -> usize {
+        4
+    }
+
+    fn parse_response(&self, buffer: &[u8]) -> Self::Response {
+        DisplayStatus(u32::from_be_bytes([
+            buffer[0], buffer[1], buffer[2], buffer[3],
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColorOrder, Orientation, RefreshOrder};
+
+    use super::*;
+
+    #[test]
+    fn rddst_parses_response() {
+        let status = ReadDisplayStatus.parse_response(&[0b1011_0100, 0b0000_0011, 0b0010_0110, 0]);
+
+        assert_eq!(ReadDisplayStatus.instruction(), 0x09);
+        assert!(status.booster_on());
+        assert_eq!(
+            status.address_mode(),
+            SetAddressMode::new(
+                ColorOrder::Bgr,
+                Orientation::Landscape(true),
+                RefreshOrder::default()
+            )
+        );
+        assert!(!status.idle_mode());
+        assert!(!status.partial_mode());
+        assert!(status.sleep_out());
+        assert!(status.normal_mode());
+        assert!(status.inverted());
+        assert!(status.display_on());
+        assert_eq!(status.tearing_effect(), TearingEffect::Vertical);
+    }
+}
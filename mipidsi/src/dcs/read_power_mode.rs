@@ -0,0 +1,77 @@
+//! Module for the RDDPM instruction
+
+use super::DcsReadCommand;
+
+/// Read Display Power Mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadPowerMode;
+
+/// Display power mode returned by [ReadPowerMode]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerMode(pub u8);
+
+impl PowerMode {
+    /// Returns `true` if the booster voltage is on.
+    pub const fn booster_on(&self) -> bool {
+        self.0 & 0b1000_0000 != 0
+    }
+
+    /// Returns `true` if idle mode is on.
+    pub const fn idle_mode(&self) -> bool {
+        self.0 & 0b0100_0000 != 0
+    }
+
+    /// Returns `true` if partial mode is on.
+    pub const fn partial_mode(&self) -> bool {
+        self.0 & 0b0010_0000 != 0
+    }
+
+    /// Returns `true` if the display is out of sleep mode.
+    pub const fn sleep_out(&self) -> bool {
+        self.0 & 0b0001_0000 != 0
+    }
+
+    /// Returns `true` if normal mode is on.
+    pub const fn normal_mode(&self) -> bool {
+        self.0 & 0b0000_1000 != 0
+    }
+
+    /// Returns `true` if the display is on.
+    pub const fn display_on(&self) -> bool {
+        self.0 & 0b0000_0100 != 0
+    }
+}
+
+impl DcsReadCommand for ReadPowerMode {
+    type Response = PowerMode;
+
+    fn instruction(&self) -> u8 {
+        0x0A
+    }
+
+    fn response_len(&self) -> usize {
+        1
+    }
+
+    fn parse_response(&self, buffer: &[u8]) -> Self::Response {
+        PowerMode(buffer[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rddpm_parses_response() {
+        let power_mode = ReadPowerMode.parse_response(&[0b1001_1100]);
+
+        assert_eq!(ReadPowerMode.instruction(), 0x0A);
+        assert!(power_mode.booster_on());
+        assert!(!power_mode.idle_mode());
+        assert!(!power_mode.partial_mode());
+        assert!(power_mode.sleep_out());
+        assert!(power_mode.normal_mode());
+        assert!(power_mode.display_on());
+    }
+}
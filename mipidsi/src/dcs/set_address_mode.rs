@@ -24,6 +24,11 @@ impl SetAddressMode {
             .with_refresh_order(refresh_order)
     }
 
+    // Creates a Set Address Mode command from a raw MADCTL value
+    pub(crate) const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
     /// Returns this Madctl with [ColorOrder] set to new value
     #[must_use]
     pub const fn with_color_order(self, color_order: ColorOrder) -> Self {
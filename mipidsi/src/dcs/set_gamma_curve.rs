@@ -0,0 +1,55 @@
+//! Module for the GAMSET gamma curve instruction constructors
+
+use crate::Error;
+
+use super::DcsCommand;
+
+/// Set Gamma Curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetGammaCurve(pub GammaCurve);
+
+impl DcsCommand for SetGammaCurve {
+    fn instruction(&self) -> u8 {
+        0x26
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0] = self.0 as u8;
+        Ok(1)
+    }
+}
+
+///
+/// Predefined gamma curves of the display controller.
+///
+/// The gamma values of the curves depend on the controller, see the datasheet of the model.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum GammaCurve {
+    /// Gamma curve 1 (GC0).
+    Curve1 = 0b0001,
+    /// Gamma curve 2 (GC1).
+    Curve2 = 0b0010,
+    /// Gamma curve 3 (GC2).
+    Curve3 = 0b0100,
+    /// Gamma curve 4 (GC3).
+    Curve4 = 0b1000,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gamset_fills_data_properly() -> Result<(), Error> {
+        let gamset = SetGammaCurve(GammaCurve::Curve3);
+
+        let mut buffer = [0u8; 1];
+        assert_eq!(gamset.instruction(), 0x26);
+        assert_eq!(gamset.fill_params_buf(&mut buffer)?, 1);
+        assert_eq!(buffer, [0b0100]);
+
+        Ok(())
+    }
+}
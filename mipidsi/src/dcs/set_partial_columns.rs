@@ -1,19 +1,19 @@
-//! Module for the CASET address window instruction constructors
+//! Module for the partial columns instruction constructors
 
 use crate::Error;
 
 use super::DcsCommand;
 
-/// Set Column Address
+/// Set Partial Columns
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SetColumnAddress {
+pub struct SetPartialColumns {
     start_column: u16,
     end_column: u16,
 }
 
-impl SetColumnAddress {
-    /// Creates a new Set Column Address command.
-    pub fn new(start_column: u16, end_column: u16) -> Self {
+impl SetPartialColumns {
+    /// Creates a new Set Partial Columns command.
+    pub const fn new(start_column: u16, end_column: u16) -> Self {
         Self {
             start_column,
             end_column,
@@ -21,9 +21,9 @@ impl SetColumnAddress {
     }
 }
 
-impl DcsCommand for SetColumnAddress {
+impl DcsCommand for SetPartialColumns {
     fn instruction(&self) -> u8 {
-        0x2A
+        0x31
     }
 
     fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
@@ -39,12 +39,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn caset_fills_data_properly() -> Result<(), Error> {
-        let caset = SetColumnAddress::new(0, 320);
+    fn set_partial_columns_fills_data_properly() -> Result<(), Error> {
+        let partial_columns = SetPartialColumns::new(0x20, 0xEF);
 
         let mut buffer = [0u8; 4];
-        assert_eq!(caset.fill_params_buf(&mut buffer)?, 4);
-        assert_eq!(buffer, [0, 0, 0x1, 0x40]);
+        assert_eq!(partial_columns.fill_params_buf(&mut buffer)?, 4);
+        assert_eq!(buffer, [0, 0x20, 0, 0xEF]);
 
         Ok(())
     }
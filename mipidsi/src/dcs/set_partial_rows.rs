@@ -1,26 +1,26 @@
-//! Module for the RASET address window instruction constructors
+//! Module for the PTLAR partial area instruction constructors
 
 use crate::Error;
 
 use super::DcsCommand;
 
-/// Set Page Address
+/// Set Partial Rows
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SetPageAddress {
+pub struct SetPartialRows {
     start_row: u16,
     end_row: u16,
 }
 
-impl SetPageAddress {
-    /// Creates a new Set Page Address command.
-    pub fn new(start_row: u16, end_row: u16) -> Self {
+impl SetPartialRows {
+    /// Creates a new Set Partial Rows command.
+    pub const fn new(start_row: u16, end_row: u16) -> Self {
         Self { start_row, end_row }
     }
 }
 
-impl DcsCommand for SetPageAddress {
+impl DcsCommand for SetPartialRows {
     fn instruction(&self) -> u8 {
-        0x2B
+        0x30
     }
 
     fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
@@ -36,12 +36,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn raset_fills_data_properly() -> Result<(), Error> {
-        let raset = SetPageAddress::new(0, 320);
+    fn ptlar_fills_data_properly() -> Result<(), Error> {
+        let ptlar = SetPartialRows::new(0x10, 0x130);
 
         let mut buffer = [0u8; 4];
-        assert_eq!(raset.fill_params_buf(&mut buffer)?, 4);
-        assert_eq!(buffer, [0, 0, 0x1, 0x40]);
+        assert_eq!(ptlar.fill_params_buf(&mut buffer)?, 4);
+        assert_eq!(buffer, [0, 0x10, 0x1, 0x30]);
 
         Ok(())
     }
@@ -0,0 +1,38 @@
+//! Module for the STE tear scanline instruction constructors
+
+use crate::Error;
+
+use super::DcsCommand;
+
+/// Set Tear Scanline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetTearScanline(pub u16);
+
+impl DcsCommand for SetTearScanline {
+    fn instruction(&self) -> u8 {
+        0x44
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0..2].copy_from_slice(&self.0.to_be_bytes());
+
+        Ok(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ste_fills_data_properly() -> Result<(), Error> {
+        let ste = SetTearScanline(0x123);
+
+        let mut buffer = [0u8; 2];
+        assert_eq!(ste.instruction(), 0x44);
+        assert_eq!(ste.fill_params_buf(&mut buffer)?, 2);
+        assert_eq!(buffer, [0x1, 0x23]);
+
+        Ok(())
+    }
+}
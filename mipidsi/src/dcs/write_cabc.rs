@@ -0,0 +1,42 @@
+//! Module for the WRCABC content adaptive brightness control instruction constructors
+
+use crate::{CabcMode, Error};
+
+use super::DcsCommand;
+
+/// Write Content Adaptive Brightness Control
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteCabc(pub CabcMode);
+
+impl DcsCommand for WriteCabc {
+    fn instruction(&self) -> u8 {
+        0x55
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0] = match self.0 {
+            CabcMode::Off => 0b00,
+            CabcMode::UserInterface => 0b01,
+            CabcMode::StillPicture => 0b10,
+            CabcMode::MovingImage => 0b11,
+        };
+        Ok(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrcabc_fills_data_properly() -> Result<(), Error> {
+        let wrcabc = WriteCabc(CabcMode::StillPicture);
+
+        let mut buffer = [0u8; 1];
+        assert_eq!(wrcabc.instruction(), 0x55);
+        assert_eq!(wrcabc.fill_params_buf(&mut buffer)?, 1);
+        assert_eq!(buffer, [0b10]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//! Module for the WRCTRLD control display instruction constructors
+
+use crate::Error;
+
+use super::DcsCommand;
+
+/// Write CTRL Display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteCtrlDisplay {
+    brightness_control: bool,
+    dimming: bool,
+    backlight: bool,
+}
+
+impl WriteCtrlDisplay {
+    /// Creates a new Write CTRL Display command.
+    ///
+    /// # Arguments
+    ///
+    /// * `brightness_control` - enables the brightness control block (BCTRL)
+    /// * `dimming` - enables smooth transitions between brightness levels (DD)
+    /// * `backlight` - turns on the backlight control output (BL)
+    pub const fn new(brightness_control: bool, dimming: bool, backlight: bool) -> Self {
+        Self {
+            brightness_control,
+            dimming,
+            backlight,
+        }
+    }
+}
+
+impl DcsCommand for WriteCtrlDisplay {
+    fn instruction(&self) -> u8 {
+        0x53
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        let mut value = 0;
+        if self.brightness_control {
+            value |= 0b0010_0000;
+        }
+        if self.dimming {
+            value |= 0b0000_1000;
+        }
+        if self.backlight {
+            value |= 0b0000_0100;
+        }
+
+        buffer[0] = value;
+        Ok(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrctrld_fills_data_properly() -> Result<(), Error> {
+        let wrctrld = WriteCtrlDisplay::new(true, false, true);
+
+        let mut buffer = [0u8; 1];
+        assert_eq!(wrctrld.fill_params_buf(&mut buffer)?, 1);
+        assert_eq!(buffer, [0b0010_0100]);
+
+        let wrctrld = WriteCtrlDisplay::new(false, true, false);
+        assert_eq!(wrctrld.fill_params_buf(&mut buffer)?, 1);
+        assert_eq!(buffer, [0b0000_1000]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//! Color helpers for idle mode.
+
+use embedded_graphics_core::pixelcolor::RgbColor;
+
+/// Maps a color to the color shown by the display in idle mode.
+///
+/// In idle mode the controller only uses the most significant bit of each color channel, which
+/// reduces the displayed colors to black, white and the six primary and secondary colors. This
+/// function can be used to preview the idle mode appearance or to pick colors which aren't
+/// changed when the display enters idle mode.
+///
+/// # Example
+/// ```rust ignore
+/// // dark orange is shown as red
+/// let color = idle_mode_color(Rgb565::new(20, 20, 0));
+/// assert_eq!(color, Rgb565::RED);
+/// ```
+pub fn idle_mode_color<C: RgbColor>(color: C) -> C {
+    let red = color.r() > C::MAX_R / 2;
+    let green = color.g() > C::MAX_G / 2;
+    let blue = color.b() > C::MAX_B / 2;
+
+    match (red, green, blue) {
+        (false, false, false) => C::BLACK,
+        (true, false, false) => C::RED,
+        (false, true, false) => C::GREEN,
+        (false, false, true) => C::BLUE,
+        (true, true, false) => C::YELLOW,
+        (true, false, true) => C::MAGENTA,
+        (false, true, true) => C::CYAN,
+        (true, true, true) => C::WHITE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics_core::pixelcolor::{Rgb565, Rgb666};
+
+    use super::*;
+
+    #[test]
+    fn idle_mode_color_uses_most_significant_bit() {
+        assert_eq!(idle_mode_color(Rgb565::new(20, 10, 31)), Rgb565::MAGENTA);
+        assert_eq!(idle_mode_color(Rgb565::new(15, 32, 0)), Rgb565::GREEN);
+        assert_eq!(idle_mode_color(Rgb666::new(32, 32, 32)), Rgb666::WHITE);
+        assert_eq!(idle_mode_color(Rgb666::new(31, 63, 31)), Rgb666::GREEN);
+    }
+}
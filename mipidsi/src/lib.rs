@@ -15,8 +15,12 @@
 //! * ST7789
 //! * ST7735
 //! * ILI9486
+//! * ILI9488
 //! * ILI9341
 //! * ILI9342C
+//! * GC9A01
+//! * ST7796S
+//! * HX8357D
 //!
 //! ## Examples
 //! **For the ili9486 display, using the SPI interface with no chip select:**
@@ -76,10 +80,14 @@
 //! ## Troubleshooting
 //! See [document](https://github.com/almindor/mipidsi/blob/master/docs/TROUBLESHOOTING.md)
 
-use core::fmt::Debug;
+use core::ops::Range;
 
-use dcs::{Dcs, AsyncDcs};
-use display_interface::{WriteOnlyDataCommand, AsyncWriteOnlyDataCommand};
+use dcs::{Dcs, ReadWriteDataCommand};
+use display_interface::WriteOnlyDataCommand;
+use embedded_graphics_core::{
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
 
 pub mod error;
 use embedded_hal::blocking::delay::DelayUs;
@@ -91,21 +99,42 @@ pub use options::*;
 
 mod builder;
 pub use builder::Builder;
-pub use builder::AsyncBuilder;
 
 pub mod dcs;
 
 pub mod models;
-use models::{Model, AsyncModel};
+use models::{BrightnessControl, Model};
 
 mod graphics;
 
+mod band;
+pub use band::Band;
+
 mod test_image;
 pub use test_image::TestImage;
 
+mod idle_mode;
+pub use idle_mode::idle_mode_color;
+
+mod window;
+pub use window::WindowWriter;
+
 #[cfg(feature = "batch")]
 mod batch;
 
+#[cfg(test)]
+mod mock;
+
+/// Helpers shared with `mipidsi-async`.
+///
+/// Not part of the public API, the contents can change in any release.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::band::Bands;
+    #[cfg(feature = "batch")]
+    pub use crate::batch::{to_blocks, to_rows, PixelBlock};
+}
+
 ///
 /// Display driver to connect to TFT displays.
 ///
@@ -127,6 +156,10 @@ where
     madctl: dcs::SetAddressMode,
     // State monitor for sleeping TODO: refactor to a Model-connected state machine
     sleeping: bool,
+    // State monitor for the display output
+    display_on: bool,
+    // State monitor for idle mode
+    idle: bool,
 }
 
 impl<DI, M, RST> Display<DI, M, RST>
@@ -213,6 +246,78 @@ where
         Ok(())
     }
 
+    ///
+    /// Returns a [WindowWriter] for streaming pixels into the given area of the display.
+    ///
+    /// Unlike [Self::set_pixels], the pixels don't need to be provided by a single iterator. The
+    /// first chunk written to the window starts a new memory write and all following chunks
+    /// continue it. Returns [Error::OutOfBoundsError] if the area is empty or not fully inside the
+    /// display.
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// let mut window = display.window(Rectangle::new(Point::zero(), Size::new(240, 240)))?;
+    /// for chunk in image_chunks {
+    ///     window.write_pixels(chunk.iter().copied())?;
+    /// }
+    /// ```
+    pub fn window(&mut self, area: Rectangle) -> Result<WindowWriter<'_, DI, M, RST>, Error> {
+        let (width, height) = self.options.display_size();
+        let display_area = Rectangle::new(
+            Point::zero(),
+            Size::new(u32::from(width), u32::from(height)),
+        );
+
+        match area.bottom_right() {
+            Some(bottom_right)
+                if display_area.contains(area.top_left) && display_area.contains(bottom_right) =>
+            {
+                self.set_address_window(
+                    area.top_left.x as u16,
+                    area.top_left.y as u16,
+                    bottom_right.x as u16,
+                    bottom_right.y as u16,
+                )?;
+
+                Ok(WindowWriter::new(self))
+            }
+            _ => Err(Error::OutOfBoundsError),
+        }
+    }
+
+    ///
+    /// Renders the display in horizontal bands using a small `buffer` instead of a full framebuffer.
+    ///
+    /// The display is split into bands of `buffer.len() / width` rows. `render` is called once per
+    /// band to draw the whole scene into a [Band], which is sent to the display afterwards.
+    /// Returns [Error::OutOfBoundsError] if the buffer can't hold a single row.
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// let mut buffer = [Rgb565::BLACK; 240 * 16];
+    /// display.render_bands(&mut buffer, |band| {
+    ///     band.clear(Rgb565::BLACK).unwrap();
+    ///     Circle::new(Point::new(120, 60), 40).into_styled(style).draw(band).unwrap();
+    /// }).unwrap();
+    /// ```
+    pub fn render_bands<F>(
+        &mut self,
+        buffer: &mut [M::ColorFormat],
+        mut render: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(&mut Band<'_, M::ColorFormat>),
+    {
+        let (width, height) = self.options.display_size();
+        let mut bands = band::Bands::new(buffer.len(), width, height)?;
+
+        while let Some((top, bottom, pixels)) = bands.render_next(buffer, &mut render) {
+            self.set_pixels(0, top, width - 1, bottom, pixels.iter().copied())?;
+        }
+
+        Ok(())
+    }
+
     ///
     /// Sets scroll region
     /// # Arguments
@@ -237,6 +342,53 @@ where
         self.dcs.write_command(vscad)
     }
 
+    ///
+    /// Selects one of the predefined gamma curves of the display controller.
+    ///
+    pub fn set_gamma_curve(&mut self, gamma_curve: dcs::GammaCurve) -> Result<(), Error> {
+        self.dcs.write_command(dcs::SetGammaCurve(gamma_curve))
+    }
+
+    ///
+    /// Sets the rows of the partial display area.
+    ///
+    /// Only the rows in the partial area are displayed after [Self::enter_partial_mode] is called,
+    /// the rest of the panel shows the background color and consumes less power. The rows are
+    /// given in the framebuffer coordinates of the controller, they aren't affected by the
+    /// display orientation.
+    ///
+    /// Returns [Error::OutOfBoundsError] if `rows` is empty or ends after the last row of the
+    /// framebuffer.
+    ///
+    /// # Example
+    /// ```rust ignore
+    /// // keep only a 20 pixel status bar lit
+    /// display.set_partial_area(0..20).unwrap();
+    /// display.enter_partial_mode().unwrap();
+    /// ```
+    pub fn set_partial_area(&mut self, rows: Range<u16>) -> Result<(), Error> {
+        if rows.is_empty() || rows.end > self.options.framebuffer_size().1 {
+            return Err(Error::OutOfBoundsError);
+        }
+
+        self.dcs
+            .write_command(dcs::SetPartialRows::new(rows.start, rows.end - 1))
+    }
+
+    ///
+    /// Enters partial mode, only the area set by [Self::set_partial_area] is displayed.
+    ///
+    pub fn enter_partial_mode(&mut self) -> Result<(), Error> {
+        self.dcs.write_command(dcs::EnterPartialMode)
+    }
+
+    ///
+    /// Leaves partial mode and displays the whole framebuffer again.
+    ///
+    pub fn enter_normal_mode(&mut self) -> Result<(), Error> {
+        self.dcs.write_command(dcs::EnterNormalMode)
+    }
+
     ///
     /// Release resources allocated to this driver back.
     /// This returns the display interface, reset pin and and the model deconstructing the driver.
@@ -263,6 +415,17 @@ where
             .write_command(dcs::SetTearingEffect(tearing_effect))
     }
 
+    ///
+    /// Sets the scanline at which the tearing effect output is triggered.
+    ///
+    /// The tearing effect output needs to be enabled using [Self::set_tearing_effect] with
+    /// [TearingEffect::Vertical]. The output is then triggered when the display refresh reaches
+    /// the given scanline instead of the start of the vertical blanking period.
+    ///
+    pub fn set_tear_scanline(&mut self, scanline: u16) -> Result<(), Error> {
+        self.dcs.write_command(dcs::SetTearScanline(scanline))
+    }
+
     ///
     /// Returns `true` if display is currently set to sleep.
     ///
@@ -293,6 +456,56 @@ where
         Ok(())
     }
 
+    ///
+    /// Returns `true` if the display output is currently turned on.
+    ///
+    pub fn is_display_on(&self) -> bool {
+        self.display_on
+    }
+
+    ///
+    /// Turns the display output on or off.
+    ///
+    /// Unlike [Self::sleep] and [Self::wake] this takes effect immediately. The display
+    /// memory is kept while the output is turned off and can still be written, which makes it
+    /// possible to blank the panel while the whole frame is redrawn.
+    ///
+    pub fn set_display_on(&mut self, on: bool) -> Result<(), Error> {
+        if on {
+            self.dcs.write_command(dcs::SetDisplayOn)?;
+        } else {
+            self.dcs.write_command(dcs::SetDisplayOff)?;
+        }
+        self.display_on = on;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns `true` if the display is currently in idle mode.
+    ///
+    pub fn is_idle_mode(&self) -> bool {
+        self.idle
+    }
+
+    ///
+    /// Enables or disables idle mode.
+    ///
+    /// In idle mode the display only shows 8 colors, which reduces power consumption. Drawing
+    /// is still possible in idle mode, but the controller reduces each color channel to its most
+    /// significant bit. Use [idle_mode_color] to get the color which is shown in idle mode.
+    ///
+    pub fn set_idle_mode(&mut self, idle: bool) -> Result<(), Error> {
+        if idle {
+            self.dcs.write_command(dcs::EnterIdleMode)?;
+        } else {
+            self.dcs.write_command(dcs::ExitIdleMode)?;
+        }
+        self.idle = idle;
+
+        Ok(())
+    }
+
     /// Returns the DCS interface for sending raw commands.
     ///
     /// # Safety
@@ -306,244 +519,211 @@ where
     }
 }
 
-
-///
-/// Display driver to connect to TFT displays.
-///
-pub struct AsyncDisplay<DI, MODEL, RST>
-where
-    DI: AsyncWriteOnlyDataCommand,
-    MODEL: AsyncModel,
-    RST: OutputPin,
-{
-    // DCS provider
-    dcs: AsyncDcs<DI>,
-    // Model
-    model: MODEL,
-    // Reset pin
-    rst: Option<RST>,
-    // Model Options, includes current orientation
-    options: ModelOptions,
-    // Current MADCTL value copy for runtime updates
-    madctl: dcs::SetAddressMode,
-    // State monitor for sleeping TODO: refactor to a Model-connected state machine
-    sleeping: bool,
-}
-
-impl<DI, M, RST> AsyncDisplay<DI, M, RST>
+impl<DI, M, RST> Display<DI, M, RST>
 where
-    DI: AsyncWriteOnlyDataCommand,
-    M: AsyncModel,
+    DI: WriteOnlyDataCommand,
+    M: Model + BrightnessControl,
     RST: OutputPin,
 {
     ///
-    /// Returns currently set [Orientation]
+    /// Sets the display brightness.
     ///
-    pub fn orientation(&self) -> Orientation {
-        self.options.orientation()
-    }
-
+    /// Enables the brightness control of the controller and sets the brightness value of the
+    /// backlight PWM output, `0` is the lowest and `255` the highest brightness.
+    /// Only available for models which implement [BrightnessControl].
     ///
-    /// Sets display [Orientation] with mirror image parameter
-    ///
-    /// # Example
-    /// ```rust ignore
-    /// display.orientation(Orientation::Portrait(false)).unwrap();
-    /// ```
-    pub async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), Error> {
-        self.madctl = self.madctl.with_orientation(orientation); // set orientation
-        self.dcs.write_command(self.madctl).await?;
-
-        Ok(())
+    pub fn set_brightness(&mut self, brightness: u8) -> Result<(), Error> {
+        self.dcs
+            .write_command(dcs::WriteCtrlDisplay::new(true, false, true))?;
+        self.dcs
+            .write_command(dcs::WriteDisplayBrightness(brightness))
     }
 
     ///
-    /// Sets a pixel color at the given coords.
-    ///
-    /// # Arguments
+    /// Sets the content adaptive brightness control (CABC) mode.
     ///
-    /// * `x` - x coordinate
-    /// * `y` - y coordinate
-    /// * `color` - the color value in pixel format of the display [Model]
+    /// Only available for models which implement [BrightnessControl].
     ///
-    /// # Example
-    /// ```rust ignore
-    /// display.set_pixel(100, 200, Rgb666::new(251, 188, 20)).unwrap();
-    /// ```
-    pub fn set_pixel(&mut self, x: u16, y: u16, color: M::ColorFormat) -> Result<(), Error> {
-        /*self.set_address_window(x, y, x, y)?;
-        self.model
-            .write_pixels(&mut self.dcs, core::iter::once(color))?;
-
-        Ok(())*/
-        self.model.write_pixel(x, y, color)?;
-        
-        Ok(())
+    pub fn set_cabc_mode(&mut self, mode: CabcMode) -> Result<(), Error> {
+        self.dcs.write_command(dcs::WriteCabc(mode))
     }
+}
 
+impl<DI, M, RST> Display<DI, M, RST>
+where
+    DI: ReadWriteDataCommand,
+    M: Model,
+    RST: OutputPin,
+{
     ///
-    /// Sets pixel colors in a rectangular region.
+    /// Reads the display identification.
     ///
-    /// The color values from the `colors` iterator will be drawn to the given region starting
-    /// at the top left corner and continuing, row first, to the bottom right corner. No bounds
-    /// checking is performed on the `colors` iterator and drawing will wrap around if the
-    /// iterator returns more color values than the number of pixels in the given region.
+    /// Can be used to verify which controller is attached to the display interface.
     ///
-    /// This is a low level function, which isn't intended to be used in regular user code.
-    /// Consider using the [`fill_contiguous`](https://docs.rs/embedded-graphics/latest/embedded_graphics/draw_target/trait.DrawTarget.html#method.fill_contiguous)
-    /// function from the `embedded-graphics` crate as an alternative instead.
+    pub fn read_display_id(&mut self) -> Result<dcs::DisplayId, Error> {
+        self.dcs.read_command(dcs::ReadDisplayId)
+    }
+
     ///
-    /// # Arguments
+    /// Reads the display status.
     ///
-    /// * `sx` - x coordinate start
-    /// * `sy` - y coordinate start
-    /// * `ex` - x coordinate end
-    /// * `ey` - y coordinate end
-    /// * `colors` - anything that can provide `IntoIterator<Item = u16>` to iterate over pixel data
-    pub fn set_pixels<T>(
-        &mut self,
-        sx: u16,
-        sy: u16,
-        ex: u16,
-        ey: u16,
-        colors: T,
-    ) -> Result<(), Error>
-    where
-        T: IntoIterator<Item = M::ColorFormat>,
-    {
-        /*self.set_address_window(sx, sy, ex, ey)?;
-        self.model.write_pixels(&mut self.dcs, colors)?;*/
-        
-        let mut x = sx;
-        let mut y = sy;
-        for color in colors {
-
-            
-            self.set_pixel(x, y, color)?;
-            
-            if x == ex {
-                if y == ey {
-                    // this was the last line, finish
-                    break;
-                }
-                // end of line, go to next line
-                y += 1;
-                x = 0;
-            } else {
-                // go to next pixel in current line
-                x += 1;
-            }
-        }
-
-        Ok(())
+    pub fn read_display_status(&mut self) -> Result<dcs::DisplayStatus, Error> {
+        self.dcs.read_command(dcs::ReadDisplayStatus)
     }
 
     ///
-    /// Sets scroll region
-    /// # Arguments
+    /// Reads the display power mode.
     ///
-    /// * `tfa` - Top fixed area
-    /// * `vsa` - Vertical scrolling area
-    /// * `bfa` - Bottom fixed area
+    /// Can be used to check if the display is awake and turned on.
     ///
-    pub async fn set_scroll_region(&mut self, tfa: u16, vsa: u16, bfa: u16) -> Result<(), Error> {
-        let vscrdef = dcs::SetScrollArea::new(tfa, vsa, bfa);
-        self.dcs.write_command(vscrdef).await
+    pub fn read_power_mode(&mut self) -> Result<dcs::PowerMode, Error> {
+        self.dcs.read_command(dcs::ReadPowerMode)
     }
 
     ///
-    /// Sets scroll offset "shifting" the displayed picture
-    /// # Arguments
-    ///
-    /// * `offset` - scroll offset in pixels
+    /// Reads the scanline which is currently refreshed by the display.
     ///
-    pub async fn set_scroll_offset(&mut self, offset: u16) -> Result<(), Error> {
-        let vscad = dcs::SetScrollStart::new(offset);
-        self.dcs.write_command(vscad).await
+    pub fn read_scanline(&mut self) -> Result<u16, Error> {
+        self.dcs.read_command(dcs::GetScanline)
     }
 
     ///
-    /// Release resources allocated to this driver back.
-    /// This returns the display interface, reset pin and and the model deconstructing the driver.
+    /// Reads the memory access control (MADCTL) value from the display.
     ///
-    pub fn release(self) -> (DI, M, Option<RST>) {
-        (self.dcs.release(), self.model, self.rst)
+    pub fn read_address_mode(&mut self) -> Result<dcs::SetAddressMode, Error> {
+        self.dcs.read_command(dcs::ReadAddressMode)
     }
+}
 
-    // Sets the address window for the display.
-    async fn set_address_window(&mut self, sx: u16, sy: u16, ex: u16, ey: u16) -> Result<(), Error> {
-        // add clipping offsets if present
-        let offset = self.options.window_offset();
-        let (sx, sy, ex, ey) = (sx + offset.0, sy + offset.1, ex + offset.0, ey + offset.1);
+#[cfg(test)]
+mod tests {
+    use embedded_graphics_core::{
+        pixelcolor::{Rgb565, RgbColor},
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    use crate::{
+        mock::{MockDelay, MockDisplayInterface, MockOutputPin},
+        models::ST7789,
+        Builder, CabcMode, Display,
+    };
+
+    // Returns an initialized ST7789 display with an empty interface log
+    fn test_display() -> Display<MockDisplayInterface, ST7789, MockOutputPin> {
+        test_display_with(MockDisplayInterface::new())
+    }
+
+    // Same as `test_display` with a display interface which returns read responses
+    fn test_display_with(
+        di: MockDisplayInterface,
+    ) -> Display<MockDisplayInterface, ST7789, MockOutputPin> {
+        let mut display = Builder::st7789(di)
+            .init(&mut MockDelay, None::<MockOutputPin>)
+            .unwrap();
+        display.dcs.di.clear();
 
-        self.dcs.write_command(dcs::SetColumnAddress::new(sx, ex)).await?;
-        self.dcs.write_command(dcs::SetPageAddress::new(sy, ey)).await
+        display
     }
 
-    ///
-    /// Configures the tearing effect output.
-    ///
-    pub async fn set_tearing_effect(&mut self, tearing_effect: TearingEffect) -> Result<(), Error> {
-        self.dcs
-            .write_command(dcs::SetTearingEffect(tearing_effect)).await
+    #[test]
+    fn read_power_mode() {
+        let mut display =
+            test_display_with(MockDisplayInterface::with_response(0x0A, &[0b1001_1100]));
+
+        let power_mode = display.read_power_mode().unwrap();
+        assert!(power_mode.sleep_out());
+        assert!(power_mode.display_on());
+        assert_eq!(display.dcs.di.log(), &[0x0A]);
+
+        // reading a different register fails with the mock response
+        assert!(display.read_display_status().is_err());
     }
 
-    ///
-    /// Returns `true` if display is currently set to sleep.
-    ///
-    pub fn is_sleeping<D: DelayUs<u32>>(&self) -> bool {
-        self.sleeping
+    #[test]
+    fn tear_scanline() {
+        let mut display =
+            test_display_with(MockDisplayInterface::with_response(0x45, &[0x00, 0x50]));
+
+        display.set_tear_scanline(0x120).unwrap();
+        assert_eq!(display.dcs.di.log(), &[0x44, 0x01, 0x20]);
+        assert_eq!(display.read_scanline().unwrap(), 0x50);
     }
 
-    ///
-    /// Puts the display to sleep, reducing power consumption.
-    /// Need to call [Self::wake] before issuing other commands
-    ///
-    pub async fn sleep<D: DelayUs<u32>>(&mut self, delay: &mut D) -> Result<(), Error> {
-        self.dcs.write_command(dcs::EnterSleepMode).await?;
-        // All supported models requires a 120ms delay before issuing other commands
-        delay.delay_us(120_000);
-        self.sleeping = true;
-        Ok(())
+    #[test]
+    fn partial_mode() {
+        let mut display = test_display();
+
+        display.set_partial_area(0..20).unwrap();
+        display.enter_partial_mode().unwrap();
+        display.enter_normal_mode().unwrap();
+        assert_eq!(display.dcs.di.log(), &[0x30, 0, 0, 0, 19, 0x12, 0x13]);
+
+        assert!(display.set_partial_area(20..20).is_err());
+
+        // the ST7789 framebuffer has 320 rows
+        display.dcs.di.clear();
+        assert!(display.set_partial_area(300..321).is_err());
+        assert_eq!(display.dcs.di.log(), &[]);
+        display.set_partial_area(300..320).unwrap();
+        assert_eq!(display.dcs.di.log(), &[0x30, 0x01, 0x2C, 0x01, 0x3F]);
     }
 
-    ///
-    /// Wakes the display after it's been set to sleep via [Self::sleep]
-    ///
-    pub async fn wake<D: DelayUs<u32>>(&mut self, delay: &mut D) -> Result<(), Error> {
-        self.dcs.write_command(dcs::ExitSleepMode).await?;
-        // ST7789 and st7735s have the highest minimal delay of 120ms
-        delay.delay_us(120_000);
-        self.sleeping = false;
-        Ok(())
+    #[test]
+    fn display_on_off() {
+        let mut display = test_display();
+        assert!(display.is_display_on());
+
+        display.set_display_on(false).unwrap();
+        assert!(!display.is_display_on());
+        display.set_display_on(true).unwrap();
+        assert!(display.is_display_on());
+        assert_eq!(display.dcs.di.log(), &[0x28, 0x29]);
     }
-    
-    /// todo: Documentation
-    pub async fn flush(&mut self) -> Result<(), Error> {
-        self.set_address_window(0, 0, 239, 134).await?;
-        self.model.flush(&mut self.dcs).await
+
+    #[test]
+    fn idle_mode() {
+        let mut display = test_display();
+        assert!(!display.is_idle_mode());
+
+        display.set_idle_mode(true).unwrap();
+        assert!(display.is_idle_mode());
+        display.set_idle_mode(false).unwrap();
+        assert!(!display.is_idle_mode());
+        assert_eq!(display.dcs.di.log(), &[0x39, 0x38]);
     }
 
-    /// Returns the DCS interface for sending raw commands.
-    ///
-    /// # Safety
-    ///
-    /// Sending raw commands to the controller can lead to undefined behaviour,
-    /// because the rest of the code isn't aware of any state changes that were caused by sending raw commands.
-    /// The user must ensure that the state of the controller isn't altered in a way that interferes with the normal
-    /// operation of this crate.
-    pub unsafe fn dcs(&mut self) -> &mut AsyncDcs<DI> {
-        &mut self.dcs
+    #[test]
+    fn window_writes_continue_memory_write() {
+        let mut display = test_display();
+
+        let mut window = display
+            .window(Rectangle::new(Point::new(1, 2), Size::new(2, 2)))
+            .unwrap();
+        window.write_pixels([Rgb565::RED; 2]).unwrap();
+        window.write_pixels([Rgb565::GREEN; 2]).unwrap();
+        assert_eq!(
+            display.dcs.di.log(),
+            &[0x2A, 0, 1, 0, 2, 0x2B, 0, 2, 0, 3, 0x2C, 0x3C]
+        );
+
+        assert!(display
+            .window(Rectangle::new(Point::new(239, 0), Size::new(2, 1)))
+            .is_err());
+        assert!(display
+            .window(Rectangle::new(Point::new(0, 0), Size::zero()))
+            .is_err());
     }
-}
 
-impl<DI, MODEL, RST> Debug for AsyncDisplay<DI, MODEL, RST> 
-where
-    DI: AsyncWriteOnlyDataCommand,
-    MODEL: AsyncModel,
-    RST: OutputPin,
-{
-    fn fmt(&self, _f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        Ok(())
+    #[test]
+    fn brightness_control() {
+        let mut display = test_display();
+
+        display.set_brightness(0x80).unwrap();
+        display.set_cabc_mode(CabcMode::MovingImage).unwrap();
+        assert_eq!(
+            display.dcs.di.log(),
+            &[0x53, 0b0010_0100, 0x51, 0x80, 0x55, 0b11]
+        );
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,112 @@
+//! Mock display interface, pin and delay for unit tests.
+
+use core::convert::Infallible;
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+
+use crate::dcs::ReadWriteDataCommand;
+
+/// Display interface which records the sent commands and returns a fixed read response.
+pub struct MockDisplayInterface {
+    // Bytes sent since the last `clear`, 16 bit pixel data isn't recorded
+    log: [u8; 256],
+    log_len: usize,
+    last_command: Option<u8>,
+    // Instruction and data returned by `read_data`
+    response: Option<(u8, &'static [u8])>,
+}
+
+impl MockDisplayInterface {
+    pub fn new() -> Self {
+        Self {
+            log: [0; 256],
+            log_len: 0,
+            last_command: None,
+            response: None,
+        }
+    }
+
+    /// Returns `data` when `instruction` is read.
+    pub fn with_response(instruction: u8, data: &'static [u8]) -> Self {
+        Self {
+            response: Some((instruction, data)),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the bytes sent since the last call to [Self::clear].
+    pub fn log(&self) -> &[u8] {
+        &self.log[..self.log_len]
+    }
+
+    pub fn clear(&mut self) {
+        self.log_len = 0;
+    }
+
+    fn record(&mut self, data: DataFormat<'_>) {
+        match data {
+            DataFormat::U8(bytes) => bytes.iter().for_each(|byte| self.push(*byte)),
+            DataFormat::U8Iter(iter) => iter.for_each(|byte| self.push(byte)),
+            _ => {}
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        if let Some(entry) = self.log.get_mut(self.log_len) {
+            *entry = byte;
+            self.log_len += 1;
+        }
+    }
+}
+
+impl WriteOnlyDataCommand for MockDisplayInterface {
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        if let DataFormat::U8(&[instruction]) = cmd {
+            self.last_command = Some(instruction);
+        }
+        self.record(cmd);
+
+        Ok(())
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.record(buf);
+
+        Ok(())
+    }
+}
+
+impl ReadWriteDataCommand for MockDisplayInterface {
+    fn read_data(&mut self, buffer: &mut [u8]) -> Result<(), DisplayError> {
+        match self.response {
+            Some((instruction, data))
+                if Some(instruction) == self.last_command && data.len() == buffer.len() =>
+            {
+                buffer.copy_from_slice(data);
+                Ok(())
+            }
+            _ => Err(DisplayError::BusWriteError),
+        }
+    }
+}
+
+pub struct MockOutputPin;
+
+impl OutputPin for MockOutputPin {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+pub struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
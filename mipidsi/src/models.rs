@@ -1,30 +1,35 @@
 //! Display models.
 
 use crate::{
-    dcs::{Dcs, SetAddressMode, AsyncDcs},
+    dcs::{BitsPerPixel, Dcs, SetAddressMode},
     error::InitError,
     Error, ModelOptions,
 };
-use display_interface::{WriteOnlyDataCommand, AsyncWriteOnlyDataCommand};
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
 use embedded_graphics_core::prelude::RgbColor;
 use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
 
 // existing model implementations
 mod gc9a01;
+mod hx8357d;
 mod ili9341;
 mod ili9342c;
 mod ili934x;
 mod ili9486;
+mod ili9488;
 mod st7735s;
 mod st7789;
+mod st7796s;
 
-use embedded_hal_async::delay::DelayNs;
 pub use gc9a01::*;
+pub use hx8357d::*;
 pub use ili9341::*;
 pub use ili9342c::*;
 pub use ili9486::*;
+pub use ili9488::*;
 pub use st7735s::*;
 pub use st7789::*;
+pub use st7796s::*;
 
 /// Display model.
 pub trait Model {
@@ -64,12 +69,49 @@ pub trait Model {
 
     /// Writes pixels to the display IC via the given display interface.
     ///
-    /// Any pixel color format conversion is done here.
-    fn write_pixels<DI, I>(&mut self, di: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    /// Starts a new memory write with [WriteMemoryStart](crate::dcs::WriteMemoryStart) before
+    /// sending the pixels. Any pixel color format conversion is done here.
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
     where
         DI: WriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>;
 
+    /// Sends pixel data to the display IC via the given display interface.
+    ///
+    /// The pixel data is sent without a preceding memory write command, which allows the data
+    /// to be sent after [WriteMemoryStart](crate::dcs::WriteMemoryStart) or
+    /// [WriteMemoryContinue](crate::dcs::WriteMemoryContinue). Used by
+    /// [`Display::window`](crate::Display::window) to continue a memory write.
+    ///
+    /// The default implementation sends 16 bit colors as big endian `u16` values and 18 or 24 bit
+    /// colors as 3 bytes in RGB order, other color formats return
+    /// [`DataFormatNotImplemented`](display_interface::DisplayError::DataFormatNotImplemented).
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        match BitsPerPixel::from_rgb_color::<Self::ColorFormat>() {
+            BitsPerPixel::Sixteen => {
+                let mut iter = colors
+                    .into_iter()
+                    .map(|c| (u16::from(c.r()) << 11) | (u16::from(c.g()) << 5) | u16::from(c.b()));
+                dcs.di.send_data(DataFormat::U16BEIter(&mut iter))
+            }
+            BitsPerPixel::Eighteen => {
+                let mut iter = colors
+                    .into_iter()
+                    .flat_map(|c| [c.r() << 2, c.g() << 2, c.b() << 2]);
+                dcs.di.send_data(DataFormat::U8Iter(&mut iter))
+            }
+            BitsPerPixel::TwentyFour => {
+                let mut iter = colors.into_iter().flat_map(|c| [c.r(), c.g(), c.b()]);
+                dcs.di.send_data(DataFormat::U8Iter(&mut iter))
+            }
+            _ => Err(DisplayError::DataFormatNotImplemented),
+        }
+    }
+
     /// Creates default [ModelOptions] for this particular [Model].
     ///
     /// This serves as a "sane default". There can be additional variants which will be provided via
@@ -77,61 +119,83 @@ pub trait Model {
     fn default_options() -> ModelOptions;
 }
 
-/// Display model.
-pub trait AsyncModel {
-    /// The color format.
-    type ColorFormat: RgbColor;
+/// Marker trait for models which support brightness and CABC control.
+///
+/// Models implementing this trait support the Write Display Brightness (0x51), Write CTRL Display
+/// (0x53) and Write Content Adaptive Brightness Control (0x55) commands, which are used by
+/// [`Display::set_brightness`](crate::Display::set_brightness) and
+/// [`Display::set_cabc_mode`](crate::Display::set_cabc_mode). The brightness is applied to the
+/// backlight PWM output of the controller, which needs to be connected to the backlight driver
+/// of the display module.
+pub trait BrightnessControl {}
 
-    /// Initializes the display for this model with MADCTL from [crate::Display]
-    /// and returns the value of MADCTL set by init
-    #[allow(async_fn_in_trait)]
-    async fn init<RST, DELAY, DI>(
-        &mut self,
-        dcs: &mut AsyncDcs<DI>,
-        delay: &mut DELAY,
-        options: &ModelOptions,
-        rst: &mut Option<RST>,
-    ) -> Result<SetAddressMode, InitError<RST::Error>>
-    where
-        RST: OutputPin,
-        DELAY: DelayNs,
-        DI: AsyncWriteOnlyDataCommand;
+/// Trait for models with a configurable gamma table.
+///
+/// The gamma table is written to the display during init, it can be set using
+/// [`Builder::with_gamma`](crate::Builder::with_gamma) to calibrate panels which differ from the
+/// defaults of the model.
+pub trait GammaControl {
+    /// Gamma table type of this model.
+    type Gamma;
 
-    /// Resets the display using a reset pin.
-    #[allow(async_fn_in_trait)]
-    async fn hard_reset<RST, DELAY>(
-        &mut self,
-        rst: &mut RST,
-        delay: &mut DELAY,
-    ) -> Result<(), InitError<RST::Error>>
-    where
-        RST: OutputPin,
-        DELAY: DelayNs,
-    {
-        rst.set_low().map_err(InitError::Pin)?;
-        delay.delay_us(10).await;
-        rst.set_high().map_err(InitError::Pin)?;
+    /// Returns the current gamma table.
+    fn gamma(&self) -> &Self::Gamma;
 
-        Ok(())
+    /// Sets the gamma table.
+    fn set_gamma(&mut self, gamma: Self::Gamma);
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics_core::pixelcolor::Rgb666;
+
+    use crate::{dcs::WriteMemoryStart, mock::MockDisplayInterface};
+
+    use super::*;
+
+    // model which only implements the required methods
+    struct MinimalRgb666;
+
+    impl Model for MinimalRgb666 {
+        type ColorFormat = Rgb666;
+
+        fn init<RST, DELAY, DI>(
+            &mut self,
+            _dcs: &mut Dcs<DI>,
+            _delay: &mut DELAY,
+            options: &ModelOptions,
+            _rst: &mut Option<RST>,
+        ) -> Result<SetAddressMode, InitError<RST::Error>>
+        where
+            RST: OutputPin,
+            DELAY: DelayUs<u32>,
+            DI: WriteOnlyDataCommand,
+        {
+            Ok(SetAddressMode::from(options))
+        }
+
+        fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+        where
+            DI: WriteOnlyDataCommand,
+            I: IntoIterator<Item = Self::ColorFormat>,
+        {
+            dcs.write_command(WriteMemoryStart)?;
+            self.write_pixel_data(dcs, colors)
+        }
+
+        fn default_options() -> ModelOptions {
+            ModelOptions::with_sizes((240, 320), (240, 320))
+        }
     }
-    
-    /// documentation
-    fn clear(&mut self, color: Self::ColorFormat) -> Result<(), Error>;
 
-    /// Writes pixels to the display IC via the given display interface.
-    ///
-    /// Any pixel color format conversion is done here.
-    fn write_pixel(&mut self, x: u16, y: u16, colors: Self::ColorFormat) -> Result<(), Error>;
+    #[test]
+    fn default_write_pixel_data_sends_3_bytes_per_18_bit_pixel() {
+        let mut dcs = Dcs::write_only(MockDisplayInterface::new());
 
-    /// Creates default [ModelOptions] for this particular [Model].
-    ///
-    /// This serves as a "sane default". There can be additional variants which will be provided via
-    /// helper constructors.
-    fn default_options() -> ModelOptions;
-    
-    /// Actually transfer the data written by [`AsyncModel::clear`] or [`AsyncModel::write_pixel`]
-    #[allow(async_fn_in_trait)]
-    async fn flush<DI>(&mut self, dcs: &mut AsyncDcs<DI>) -> Result<(), Error>
-    where
-        DI: AsyncWriteOnlyDataCommand;
-}
\ No newline at end of file
+        MinimalRgb666
+            .write_pixel_data(&mut dcs, [Rgb666::new(0x3F, 0x01, 0x20)])
+            .unwrap();
+
+        assert_eq!(dcs.di.log(), &[0xFC, 0x04, 0x80]);
+    }
+}
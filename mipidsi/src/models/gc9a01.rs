@@ -11,10 +11,45 @@ use crate::{
     Builder, Error, ModelOptions,
 };
 
-use super::{Dcs, Model};
+use super::{Dcs, GammaControl, Model};
 
 /// GC9A01 display in Rgb565 color mode.
-pub struct GC9A01;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GC9A01 {
+    gamma: GC9A01Gamma,
+}
+
+/// GC9A01 gamma table.
+///
+/// Contains the parameters of the SET_GAMMA1 to SET_GAMMA4 registers (0xF0 to 0xF3), see the
+/// GC9A01 datasheet for the meaning of the individual values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GC9A01Gamma {
+    /// SET_GAMMA1 parameters.
+    pub gamma1: [u8; 6],
+    /// SET_GAMMA2 parameters.
+    pub gamma2: [u8; 6],
+    /// SET_GAMMA3 parameters.
+    pub gamma3: [u8; 6],
+    /// SET_GAMMA4 parameters.
+    pub gamma4: [u8; 6],
+}
+
+impl GC9A01Gamma {
+    /// Gamma table which is used if no other table is set.
+    pub const DEFAULT: Self = Self {
+        gamma1: [0x45, 0x09, 0x08, 0x08, 0x26, 0x2A],
+        gamma2: [0x43, 0x70, 0x72, 0x36, 0x37, 0x6f],
+        gamma3: [0x45, 0x09, 0x08, 0x08, 0x26, 0x2A],
+        gamma4: [0x43, 0x70, 0x72, 0x36, 0x37, 0x6f],
+    };
+}
+
+impl Default for GC9A01Gamma {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
 
 impl Model for GC9A01 {
     type ColorFormat = Rgb565;
@@ -78,10 +113,10 @@ impl Model for GC9A01 {
         dcs.write_raw(0xE1, &[0x10, 0x0E])?;
         dcs.write_raw(0xDF, &[0x20, 0x0c, 0x02])?;
 
-        dcs.write_raw(0xF0, &[0x45, 0x09, 0x08, 0x08, 0x26, 0x2A])?; // gamma 1
-        dcs.write_raw(0xF1, &[0x43, 0x70, 0x72, 0x36, 0x37, 0x6f])?; // gamma 2
-        dcs.write_raw(0xF2, &[0x45, 0x09, 0x08, 0x08, 0x26, 0x2A])?; // gamma 3
-        dcs.write_raw(0xF3, &[0x43, 0x70, 0x72, 0x36, 0x37, 0x6f])?; // gamma 4
+        dcs.write_raw(0xF0, &self.gamma.gamma1)?; // gamma 1
+        dcs.write_raw(0xF1, &self.gamma.gamma2)?; // gamma 2
+        dcs.write_raw(0xF2, &self.gamma.gamma3)?; // gamma 3
+        dcs.write_raw(0xF3, &self.gamma.gamma4)?; // gamma 4
 
         dcs.write_raw(0xED, &[0x18, 0x0B])?;
         dcs.write_raw(0xAE, &[0x77])?;
@@ -135,6 +170,14 @@ impl Model for GC9A01 {
         I: IntoIterator<Item = Self::ColorFormat>,
     {
         dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
         let mut iter = colors.into_iter().map(|c| c.into_storage());
 
         let buf = DataFormat::U16BEIter(&mut iter);
@@ -147,6 +190,18 @@ impl Model for GC9A01 {
     }
 }
 
+impl GammaControl for GC9A01 {
+    type Gamma = GC9A01Gamma;
+
+    fn gamma(&self) -> &Self::Gamma {
+        &self.gamma
+    }
+
+    fn set_gamma(&mut self, gamma: Self::Gamma) {
+        self.gamma = gamma;
+    }
+}
+
 // simplified constructor on Display
 
 impl<DI> Builder<DI, GC9A01>
@@ -162,6 +217,6 @@ where
     /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
     ///
     pub fn gc9a01(di: DI) -> Self {
-        Self::with_model(di, GC9A01)
+        Self::with_model(di, GC9A01::default())
     }
 }
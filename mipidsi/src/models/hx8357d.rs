@@ -0,0 +1,306 @@
+use display_interface::{DataFormat, WriteOnlyDataCommand};
+use embedded_graphics_core::{
+    pixelcolor::{Rgb565, Rgb666},
+    prelude::{IntoStorage, RgbColor},
+};
+use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+
+use crate::{
+    dcs::{
+        BitsPerPixel, Dcs, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
+        SetDisplayOn, SetInvertMode, SetPixelFormat, SoftReset, WriteMemoryStart,
+    },
+    error::InitError,
+    Builder, Error, ModelOptions,
+};
+
+use super::{GammaControl, Model};
+
+/// HX8357D display in Rgb565 color mode.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HX8357DRgb565 {
+    gamma: HX8357DGamma,
+}
+
+/// HX8357D display in Rgb666 color mode.
+///
+/// Pixels are sent as 3 bytes per pixel.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HX8357DRgb666 {
+    gamma: HX8357DGamma,
+}
+
+/// HX8357D gamma table.
+///
+/// Contains the positive and negative parameters of the gamma curve register (SETGAMMA, 0xE0),
+/// see the HX8357D datasheet for the meaning of the individual values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HX8357DGamma {
+    /// Positive gamma curve parameters.
+    pub positive: [u8; 16],
+    /// Negative gamma curve parameters.
+    pub negative: [u8; 16],
+}
+
+impl HX8357DGamma {
+    /// Gamma table which is used if no other table is set.
+    pub const DEFAULT: Self = Self {
+        positive: [
+            0x02, 0x0A, 0x11, 0x1D, 0x23, 0x35, 0x41, 0x4B, 0x4B, 0x42, 0x3A, 0x27, 0x1B, 0x08,
+            0x09, 0x03,
+        ],
+        negative: [
+            0x02, 0x0A, 0x11, 0x1D, 0x23, 0x35, 0x41, 0x4B, 0x4B, 0x42, 0x3A, 0x27, 0x1B, 0x08,
+            0x09, 0x03,
+        ],
+    };
+
+    /// Returns the parameters sent with the SETGAMMA command.
+    ///
+    /// The positive and negative parameters are followed by two fixed bytes.
+    pub fn parameters(&self) -> [u8; 34] {
+        let mut parameters = [0; 34];
+        parameters[..16].copy_from_slice(&self.positive);
+        parameters[16..32].copy_from_slice(&self.negative);
+        parameters[32..].copy_from_slice(&[0x00, 0x01]);
+
+        parameters
+    }
+}
+
+impl Default for HX8357DGamma {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Model for HX8357DRgb565 {
+    type ColorFormat = Rgb565;
+
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayUs<u32>,
+        DI: WriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => self.hard_reset(rst, delay)?,
+            None => dcs.write_command(SoftReset)?,
+        }
+        delay.delay_us(10_000);
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common(dcs, delay, options, pf, &self.gamma)?)
+    }
+
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        let mut iter = colors.into_iter().map(|c| c.into_storage());
+
+        let buf = DataFormat::U16BEIter(&mut iter);
+        dcs.di.send_data(buf)
+    }
+
+    fn default_options() -> ModelOptions {
+        default_options()
+    }
+}
+
+impl Model for HX8357DRgb666 {
+    type ColorFormat = Rgb666;
+
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayUs<u32>,
+        DI: WriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => self.hard_reset(rst, delay)?,
+            None => dcs.write_command(SoftReset)?,
+        }
+        delay.delay_us(10_000);
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common(dcs, delay, options, pf, &self.gamma)?)
+    }
+
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        let mut iter = colors.into_iter().flat_map(|c| {
+            let red = c.r() << 2;
+            let green = c.g() << 2;
+            let blue = c.b() << 2;
+            [red, green, blue]
+        });
+
+        let buf = DataFormat::U8Iter(&mut iter);
+        dcs.di.send_data(buf)
+    }
+
+    fn default_options() -> ModelOptions {
+        default_options()
+    }
+}
+
+impl GammaControl for HX8357DRgb565 {
+    type Gamma = HX8357DGamma;
+
+    fn gamma(&self) -> &Self::Gamma {
+        &self.gamma
+    }
+
+    fn set_gamma(&mut self, gamma: Self::Gamma) {
+        self.gamma = gamma;
+    }
+}
+
+impl GammaControl for HX8357DRgb666 {
+    type Gamma = HX8357DGamma;
+
+    fn gamma(&self) -> &Self::Gamma {
+        &self.gamma
+    }
+
+    fn set_gamma(&mut self, gamma: Self::Gamma) {
+        self.gamma = gamma;
+    }
+}
+
+// simplified constructor for Display
+
+impl<DI> Builder<DI, HX8357DRgb565>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Creates a new display builder for a HX8357D display in Rgb565 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn hx8357d_rgb565(di: DI) -> Self {
+        Self::with_model(di, HX8357DRgb565::default())
+    }
+}
+
+impl<DI> Builder<DI, HX8357DRgb666>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Creates a new display builder for a HX8357D display in Rgb666 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn hx8357d_rgb666(di: DI) -> Self {
+        Self::with_model(di, HX8357DRgb666::default())
+    }
+}
+
+// default options for all color format models
+fn default_options() -> ModelOptions {
+    ModelOptions::with_sizes((320, 480), (320, 480))
+}
+
+// common init for all color format models
+fn init_common<DELAY, DI>(
+    dcs: &mut Dcs<DI>,
+    delay: &mut DELAY,
+    options: &ModelOptions,
+    pixel_format: PixelFormat,
+    gamma: &HX8357DGamma,
+) -> Result<SetAddressMode, Error>
+where
+    DELAY: DelayUs<u32>,
+    DI: WriteOnlyDataCommand,
+{
+    let madctl = SetAddressMode::from(options);
+
+    dcs.write_raw(0xB9, &[0xFF, 0x83, 0x57])?; // SETC, enable extended commands
+    delay.delay_us(300_000);
+
+    dcs.write_raw(0xB3, &[0x80, 0x00, 0x06, 0x06])?; // SETRGB, enable SDO pin
+    dcs.write_raw(0xB6, &[0x25])?; // SETCOM, VCOMDC -1.52V
+    dcs.write_raw(0xB0, &[0x68])?; // SETOSC, normal mode 70Hz, idle mode 55Hz
+    dcs.write_raw(0xCC, &[0x05])?; // SETPANEL, BGR panel, gate direction swapped
+    dcs.write_raw(0xB1, &[0x00, 0x15, 0x1C, 0x1C, 0x83, 0xAA])?; // SETPWR1, power control
+    dcs.write_raw(0xC0, &[0x50, 0x50, 0x01, 0x3C, 0x1E, 0x08])?; // SETSTBA, source driver timing
+    dcs.write_raw(0xB4, &[0x02, 0x40, 0x00, 0x2A, 0x2A, 0x0D, 0x78])?; // SETCYC, display cycle
+    dcs.write_raw(0xE0, &gamma.parameters())?; // SETGAMMA, gamma curve
+
+    dcs.write_command(SetPixelFormat::new(pixel_format))?; // pixel format
+    dcs.write_command(madctl)?; // set memory data access control
+    dcs.write_command(SetInvertMode(options.invert_colors))?;
+
+    dcs.write_command(ExitSleepMode)?; // turn off sleep
+    delay.delay_us(150_000);
+
+    dcs.write_command(EnterNormalMode)?; // turn to normal mode
+    dcs.write_command(SetDisplayOn)?; // turn on display
+    delay.delay_us(50_000);
+
+    Ok(madctl)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock::{MockDelay, MockDisplayInterface, MockOutputPin};
+
+    use super::*;
+
+    #[test]
+    fn init_enables_extended_commands_first() {
+        let display = Builder::hx8357d_rgb565(MockDisplayInterface::new())
+            .init(&mut MockDelay, None::<MockOutputPin>)
+            .unwrap();
+
+        let log = display.dcs.di.log();
+        assert_eq!(&log[..5], &[0x01, 0xB9, 0xFF, 0x83, 0x57]);
+        let gamma = log.iter().position(|&b| b == 0xE0).unwrap();
+        assert_eq!(&log[gamma + 1..gamma + 3], &[0x02, 0x0A]);
+        assert_eq!(&log[gamma + 33..gamma + 37], &[0x00, 0x01, 0x3A, 0x55]);
+        assert_eq!(&log[log.len() - 3..], &[0x11, 0x13, 0x29]);
+    }
+}
@@ -3,9 +3,9 @@ use embedded_graphics_core::pixelcolor::{Rgb565, Rgb666};
 use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
 
 use crate::{
-    dcs::{BitsPerPixel, Dcs, PixelFormat, SetAddressMode, SoftReset},
+    dcs::{BitsPerPixel, Dcs, PixelFormat, SetAddressMode, SoftReset, WriteMemoryStart},
     error::InitError,
-    models::{ili934x, Model},
+    models::{ili934x, BrightnessControl, Model},
     Builder, Error, ModelOptions,
 };
 
@@ -44,7 +44,16 @@ impl Model for ILI9342CRgb565 {
         DI: WriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>,
     {
-        ili934x::write_pixels_rgb565(dcs, colors)
+        dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        ili934x::write_pixel_data_rgb565(dcs, colors)
     }
 
     fn default_options() -> ModelOptions {
@@ -81,7 +90,16 @@ impl Model for ILI9342CRgb666 {
         DI: WriteOnlyDataCommand,
         I: IntoIterator<Item = Self::ColorFormat>,
     {
-        ili934x::write_pixels_rgb666(dcs, colors)
+        dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        ili934x::write_pixel_data_rgb666(dcs, colors)
     }
 
     fn default_options() -> ModelOptions {
@@ -89,6 +107,10 @@ impl Model for ILI9342CRgb666 {
     }
 }
 
+impl BrightnessControl for ILI9342CRgb565 {}
+
+impl BrightnessControl for ILI9342CRgb666 {}
+
 // simplified constructor for Display
 
 impl<DI> Builder<DI, ILI9342CRgb565>
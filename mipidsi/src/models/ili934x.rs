@@ -5,7 +5,7 @@ use embedded_hal::blocking::delay::DelayUs;
 use crate::{
     dcs::{
         Dcs, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode, SetDisplayOn,
-        SetInvertMode, SetPixelFormat, WriteMemoryStart,
+        SetInvertMode, SetPixelFormat,
     },
     Error, ModelOptions,
 };
@@ -50,24 +50,22 @@ where
     Ok(madctl)
 }
 
-pub fn write_pixels_rgb565<DI, I>(dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+pub fn write_pixel_data_rgb565<DI, I>(dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
 where
     DI: WriteOnlyDataCommand,
     I: IntoIterator<Item = Rgb565>,
 {
-    dcs.write_command(WriteMemoryStart)?;
     let mut iter = colors.into_iter().map(|c| c.into_storage());
 
     let buf = DataFormat::U16BEIter(&mut iter);
     dcs.di.send_data(buf)
 }
 
-pub fn write_pixels_rgb666<DI, I>(dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+pub fn write_pixel_data_rgb666<DI, I>(dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
 where
     DI: WriteOnlyDataCommand,
     I: IntoIterator<Item = Rgb666>,
 {
-    dcs.write_command(WriteMemoryStart)?;
     let mut iter = colors.into_iter().flat_map(|c| {
         let red = c.r() << 2;
         let green = c.g() << 2;
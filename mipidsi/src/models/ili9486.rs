@@ -14,7 +14,7 @@ use crate::{
     Builder, Error, ModelOptions,
 };
 
-use super::Model;
+use super::{BrightnessControl, Model};
 
 /// ILI9486 display in Rgb565 color mode.
 pub struct ILI9486Rgb565;
@@ -53,6 +53,14 @@ impl Model for ILI9486Rgb565 {
         I: IntoIterator<Item = Self::ColorFormat>,
     {
         dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
         let mut iter = colors.into_iter().map(|c| c.into_storage());
 
         let buf = DataFormat::U16BEIter(&mut iter);
@@ -96,6 +104,14 @@ impl Model for ILI9486Rgb666 {
         I: IntoIterator<Item = Self::ColorFormat>,
     {
         dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
         let mut iter = colors.into_iter().flat_map(|c| {
             let red = c.r() << 2;
             let green = c.g() << 2;
@@ -112,6 +128,10 @@ impl Model for ILI9486Rgb666 {
     }
 }
 
+impl BrightnessControl for ILI9486Rgb565 {}
+
+impl BrightnessControl for ILI9486Rgb666 {}
+
 // simplified constructor for Display
 
 impl<DI> Builder<DI, ILI9486Rgb565>
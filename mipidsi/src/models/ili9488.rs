@@ -0,0 +1,317 @@
+use display_interface::{DataFormat, WriteOnlyDataCommand};
+use embedded_graphics_core::{
+    pixelcolor::{Rgb565, Rgb666},
+    prelude::{IntoStorage, RgbColor},
+};
+use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+
+use crate::{
+    dcs::{
+        BitsPerPixel, Dcs, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
+        SetDisplayOn, SetInvertMode, SetPixelFormat, SoftReset, WriteMemoryStart,
+    },
+    error::InitError,
+    Builder, ColorOrder, Error, ModelOptions,
+};
+
+use super::{BrightnessControl, GammaControl, Model};
+
+/// ILI9488 display in Rgb565 color mode.
+///
+/// # Limitations
+///
+/// The ILI9488 only supports 18 bit pixels over SPI, use [ILI9488Rgb666] for displays with SPI
+/// connection. The Rgb565 color mode can only be used with a parallel interface.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ILI9488Rgb565 {
+    gamma: ILI9488Gamma,
+}
+
+/// ILI9488 display in Rgb666 color mode.
+///
+/// Pixels are always sent as 3 bytes per pixel, which is supported by both SPI and parallel
+/// interfaces.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ILI9488Rgb666 {
+    gamma: ILI9488Gamma,
+}
+
+/// ILI9488 gamma table.
+///
+/// Contains the parameters of the positive (PGAMCTRL, 0xE0) and negative (NGAMCTRL, 0xE1) gamma
+/// control registers, see the ILI9488 datasheet for the meaning of the individual values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ILI9488Gamma {
+    /// Positive gamma control parameters.
+    pub positive: [u8; 15],
+    /// Negative gamma control parameters.
+    pub negative: [u8; 15],
+}
+
+impl ILI9488Gamma {
+    /// Gamma table which is used if no other table is set.
+    pub const DEFAULT: Self = Self {
+        positive: [
+            0x00, 0x03, 0x09, 0x08, 0x16, 0x0A, 0x3F, 0x78, 0x4C, 0x09, 0x0A, 0x08, 0x16, 0x1A,
+            0x0F,
+        ],
+        negative: [
+            0x00, 0x16, 0x19, 0x03, 0x0F, 0x05, 0x32, 0x45, 0x46, 0x04, 0x0E, 0x0D, 0x35, 0x37,
+            0x0F,
+        ],
+    };
+}
+
+impl Default for ILI9488Gamma {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Model for ILI9488Rgb565 {
+    type ColorFormat = Rgb565;
+
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayUs<u32>,
+        DI: WriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => self.hard_reset(rst, delay)?,
+            None => dcs.write_command(SoftReset)?,
+        }
+        delay.delay_us(120_000);
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common(dcs, delay, options, pf, &self.gamma)?)
+    }
+
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        let mut iter = colors.into_iter().map(|c| c.into_storage());
+
+        let buf = DataFormat::U16BEIter(&mut iter);
+        dcs.di.send_data(buf)
+    }
+
+    fn default_options() -> ModelOptions {
+        default_options()
+    }
+}
+
+impl Model for ILI9488Rgb666 {
+    type ColorFormat = Rgb666;
+
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayUs<u32>,
+        DI: WriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => self.hard_reset(rst, delay)?,
+            None => dcs.write_command(SoftReset)?,
+        }
+        delay.delay_us(120_000);
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common(dcs, delay, options, pf, &self.gamma)?)
+    }
+
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        let mut iter = colors.into_iter().flat_map(|c| {
+            let red = c.r() << 2;
+            let green = c.g() << 2;
+            let blue = c.b() << 2;
+            [red, green, blue]
+        });
+
+        let buf = DataFormat::U8Iter(&mut iter);
+        dcs.di.send_data(buf)
+    }
+
+    fn default_options() -> ModelOptions {
+        default_options()
+    }
+}
+
+impl GammaControl for ILI9488Rgb565 {
+    type Gamma = ILI9488Gamma;
+
+    fn gamma(&self) -> &Self::Gamma {
+        &self.gamma
+    }
+
+    fn set_gamma(&mut self, gamma: Self::Gamma) {
+        self.gamma = gamma;
+    }
+}
+
+impl GammaControl for ILI9488Rgb666 {
+    type Gamma = ILI9488Gamma;
+
+    fn gamma(&self) -> &Self::Gamma {
+        &self.gamma
+    }
+
+    fn set_gamma(&mut self, gamma: Self::Gamma) {
+        self.gamma = gamma;
+    }
+}
+
+impl BrightnessControl for ILI9488Rgb565 {}
+
+impl BrightnessControl for ILI9488Rgb666 {}
+
+// simplified constructor for Display
+
+impl<DI> Builder<DI, ILI9488Rgb565>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9488 display in Rgb565 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Limitations
+    ///
+    /// The ILI9488 only supports 18 bit pixels over SPI, use [`Builder::ili9488_rgb666`] for
+    /// displays with SPI connection. The Rgb565 color mode can only be used with a parallel
+    /// interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn ili9488_rgb565(di: DI) -> Self {
+        Self::with_model(di, ILI9488Rgb565::default())
+    }
+}
+
+impl<DI> Builder<DI, ILI9488Rgb666>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ILI9488 display in Rgb666 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn ili9488_rgb666(di: DI) -> Self {
+        Self::with_model(di, ILI9488Rgb666::default())
+    }
+}
+
+// default options for all color format models
+fn default_options() -> ModelOptions {
+    let mut options = ModelOptions::with_sizes((320, 480), (320, 480));
+    // most ILI9488 modules use a BGR panel
+    options.set_color_order(ColorOrder::Bgr);
+
+    options
+}
+
+// common init for all color format models
+fn init_common<DELAY, DI>(
+    dcs: &mut Dcs<DI>,
+    delay: &mut DELAY,
+    options: &ModelOptions,
+    pixel_format: PixelFormat,
+    gamma: &ILI9488Gamma,
+) -> Result<SetAddressMode, Error>
+where
+    DELAY: DelayUs<u32>,
+    DI: WriteOnlyDataCommand,
+{
+    let madctl = SetAddressMode::from(options);
+
+    dcs.write_raw(0xE0, &gamma.positive)?; // positive gamma control
+    dcs.write_raw(0xE1, &gamma.negative)?; // negative gamma control
+    dcs.write_raw(0xC0, &[0x17, 0x15])?; // power control 1
+    dcs.write_raw(0xC1, &[0x41])?; // power control 2
+    dcs.write_raw(0xC5, &[0x00, 0x12, 0x80])?; // VCOM control
+
+    dcs.write_command(madctl)?; // set memory data access control
+    dcs.write_command(SetPixelFormat::new(pixel_format))?; // pixel format
+    dcs.write_raw(0xB0, &[0x00])?; // interface mode control
+    dcs.write_raw(0xB1, &[0xA0])?; // frame rate control, 60Hz
+    dcs.write_raw(0xB4, &[0x02])?; // 2-dot inversion control
+    dcs.write_raw(0xB6, &[0x02, 0x02, 0x3B])?; // DFC
+    dcs.write_raw(0xB7, &[0xC6])?; // entry mode set
+    dcs.write_raw(0xF7, &[0xA9, 0x51, 0x2C, 0x82])?; // adjust control 3
+    dcs.write_command(SetInvertMode(options.invert_colors))?;
+
+    dcs.write_command(ExitSleepMode)?; // turn off sleep
+    delay.delay_us(120_000);
+
+    dcs.write_command(EnterNormalMode)?; // turn to normal mode
+    dcs.write_command(SetDisplayOn)?; // turn on display
+
+    // DISPON requires some time otherwise we risk SPI data issues
+    delay.delay_us(120_000);
+
+    Ok(madctl)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock::{MockDelay, MockDisplayInterface, MockOutputPin};
+
+    use super::*;
+
+    #[test]
+    fn rgb666_sends_3_bytes_per_pixel() {
+        let mut display = Builder::ili9488_rgb666(MockDisplayInterface::new())
+            .init(&mut MockDelay, None::<MockOutputPin>)
+            .unwrap();
+
+        display.dcs.di.clear();
+        display
+            .set_pixels(0, 0, 1, 0, [Rgb666::new(63, 0, 1), Rgb666::WHITE])
+            .unwrap();
+        let log = display.dcs.di.log();
+        let ramwr = log.iter().position(|&byte| byte == 0x2C).unwrap();
+        assert_eq!(&log[ramwr..], &[0x2C, 0xFC, 0x00, 0x04, 0xFC, 0xFC, 0xFC]);
+    }
+}
@@ -8,13 +8,51 @@ use crate::{
         SetPixelFormat, SoftReset, WriteMemoryStart,
     },
     error::InitError,
-    Builder, ColorInversion, Error, ModelOptions,
+    ColorInversion, Error, ModelOptions,
 };
 
-use super::{Dcs, Model};
+use super::{Dcs, GammaControl, Model};
+
+/// Module containing all ST7735s variants.
+mod variants;
 
 /// ST7735s display in Rgb565 color mode.
-pub struct ST7735s;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ST7735s {
+    gamma: ST7735sGamma,
+}
+
+/// ST7735s gamma table.
+///
+/// Contains the parameters of the positive (GMCTRP1, 0xE0) and negative (GMCTRN1, 0xE1) gamma
+/// correction registers, see the ST7735S datasheet for the meaning of the individual values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ST7735sGamma {
+    /// Positive polarity gamma correction parameters.
+    pub positive: [u8; 16],
+    /// Negative polarity gamma correction parameters.
+    pub negative: [u8; 16],
+}
+
+impl ST7735sGamma {
+    /// Gamma table which is used if no other table is set.
+    pub const DEFAULT: Self = Self {
+        positive: [
+            0x10, 0x0E, 0x02, 0x03, 0x0E, 0x07, 0x02, 0x07, 0x0A, 0x12, 0x27, 0x37, 0x00, 0x0D,
+            0x0E, 0x10,
+        ],
+        negative: [
+            0x10, 0x0E, 0x03, 0x03, 0x0F, 0x06, 0x02, 0x08, 0x0A, 0x13, 0x26, 0x36, 0x00, 0x0D,
+            0x0E, 0x10,
+        ],
+    };
+}
+
+impl Default for ST7735sGamma {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
 
 impl Model for ST7735s {
     type ColorFormat = Rgb565;
@@ -53,20 +91,8 @@ impl Model for ST7735s {
         dcs.write_raw(0xC3, &[0x8D, 0x6A])?; // set power control 4
         dcs.write_raw(0xC4, &[0x8D, 0xEE])?; // set power control 5
         dcs.write_raw(0xC5, &[0x0E])?; // set VCOM control 1
-        dcs.write_raw(
-            0xE0,
-            &[
-                0x10, 0x0E, 0x02, 0x03, 0x0E, 0x07, 0x02, 0x07, 0x0A, 0x12, 0x27, 0x37, 0x00, 0x0D,
-                0x0E, 0x10,
-            ],
-        )?; // set GAMMA +Polarity characteristics
-        dcs.write_raw(
-            0xE1,
-            &[
-                0x10, 0x0E, 0x03, 0x03, 0x0F, 0x06, 0x02, 0x08, 0x0A, 0x13, 0x26, 0x36, 0x00, 0x0D,
-                0x0E, 0x10,
-            ],
-        )?; // set GAMMA -Polarity characteristics
+        dcs.write_raw(0xE0, &self.gamma.positive)?; // set GAMMA +Polarity characteristics
+        dcs.write_raw(0xE1, &self.gamma.negative)?; // set GAMMA -Polarity characteristics
 
         let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
         dcs.write_command(SetPixelFormat::new(pf))?; // set interface pixel format, 16bit pixel into frame memory
@@ -83,6 +109,14 @@ impl Model for ST7735s {
         I: IntoIterator<Item = Self::ColorFormat>,
     {
         dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
         let mut iter = colors.into_iter().map(|c| c.into_storage());
 
         let buf = DataFormat::U16BEIter(&mut iter);
@@ -98,21 +132,42 @@ impl Model for ST7735s {
     }
 }
 
-// simplified constructor on Display
-
-impl<DI> Builder<DI, ST7735s>
-where
-    DI: WriteOnlyDataCommand,
-{
-    /// Creates a new display builder for ST7735s displays in Rgb565 color mode.
-    ///
-    /// The default framebuffer size is 132x162 pixels and display size is 80x160 pixels.
-    ///
-    /// # Arguments
-    ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
-    ///
-    pub fn st7735s(di: DI) -> Self {
-        Self::with_model(di, ST7735s)
+impl GammaControl for ST7735s {
+    type Gamma = ST7735sGamma;
+
+    fn gamma(&self) -> &Self::Gamma {
+        &self.gamma
+    }
+
+    fn set_gamma(&mut self, gamma: Self::Gamma) {
+        self.gamma = gamma;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mock::{MockDelay, MockDisplayInterface, MockOutputPin},
+        Builder,
+    };
+
+    use super::*;
+
+    #[test]
+    fn gamma_table_is_written_during_init() {
+        let gamma = ST7735sGamma {
+            positive: [1; 16],
+            negative: [2; 16],
+        };
+        let display = Builder::st7735s(MockDisplayInterface::new())
+            .with_gamma(gamma)
+            .init(&mut MockDelay, None::<MockOutputPin>)
+            .unwrap();
+
+        let log = display.dcs.di.log();
+        let positive = log.iter().position(|&byte| byte == 0xE0).unwrap();
+        assert_eq!(log[positive + 1..positive + 17], [1; 16]);
+        assert_eq!(log[positive + 17], 0xE1);
+        assert_eq!(log[positive + 18..positive + 34], [2; 16]);
     }
 }
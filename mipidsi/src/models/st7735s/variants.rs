@@ -0,0 +1,167 @@
+use display_interface::WriteOnlyDataCommand;
+
+use crate::{options::panel_offset, Builder, ColorInversion, ColorOrder, ModelOptions};
+
+use super::ST7735s;
+
+impl<DI> Builder<DI, ST7735s>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Creates a new display builder for ST7735s displays in Rgb565 color mode.
+    ///
+    /// The default framebuffer size is 132x162 pixels and display size is 80x160 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s(di: DI) -> Self {
+        Self::with_model(di, ST7735s::default())
+    }
+
+    /// Creates a new display builder for the green tab variant of a ST7735s display in Rgb565
+    /// color mode.
+    ///
+    /// The green tab variant uses a display size of 128x160 inside a 132x162 framebuffer and BGR
+    /// subpixel order.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s_green_tab(di: DI) -> Self {
+        Self::new(di, ST7735s::default(), ST7735s::green_tab_options())
+    }
+
+    /// Creates a new display builder for the red tab variant of a ST7735s display in Rgb565
+    /// color mode.
+    ///
+    /// The red tab variant uses a display and framebuffer size of 128x160 and BGR subpixel order.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s_red_tab(di: DI) -> Self {
+        Self::new(di, ST7735s::default(), ST7735s::red_tab_options())
+    }
+
+    /// Creates a new display builder for the black tab variant of a ST7735s display in Rgb565
+    /// color mode.
+    ///
+    /// The black tab variant uses a display and framebuffer size of 128x160 and RGB subpixel
+    /// order.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s_black_tab(di: DI) -> Self {
+        Self::new(di, ST7735s::default(), ST7735s::black_tab_options())
+    }
+
+    /// Creates a new display builder for the 0.96" 80x160 mini variant of a ST7735s display in
+    /// Rgb565 color mode.
+    ///
+    /// The mini variant uses a display size of 80x160 inside a 132x162 framebuffer, BGR subpixel
+    /// order and inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7735s_mini(di: DI) -> Self {
+        Self::new(di, ST7735s::default(), ST7735s::mini_options())
+    }
+}
+
+impl ST7735s {
+    /// Returns the [ModelOptions] of the green tab variant of a ST7735s display.
+    ///
+    /// The green tab variant uses a display size of 128x160 inside a 132x162 framebuffer and BGR
+    /// subpixel order.
+    pub fn green_tab_options() -> ModelOptions {
+        let mut options = ModelOptions::with_all((128, 160), (132, 162), green_tab_offset);
+        options.set_color_order(ColorOrder::Bgr);
+
+        options
+    }
+
+    /// Returns the [ModelOptions] of the red tab variant of a ST7735s display.
+    ///
+    /// The red tab variant uses a display and framebuffer size of 128x160 and BGR subpixel order.
+    pub fn red_tab_options() -> ModelOptions {
+        let mut options = ModelOptions::with_sizes((128, 160), (128, 160));
+        options.set_color_order(ColorOrder::Bgr);
+
+        options
+    }
+
+    /// Returns the [ModelOptions] of the black tab variant of a ST7735s display.
+    ///
+    /// The black tab variant uses a display and framebuffer size of 128x160 and RGB subpixel
+    /// order.
+    pub fn black_tab_options() -> ModelOptions {
+        ModelOptions::with_sizes((128, 160), (128, 160))
+    }
+
+    /// Returns the [ModelOptions] of the 0.96" 80x160 mini variant of a ST7735s display.
+    ///
+    /// The mini variant uses a display size of 80x160 inside a 132x162 framebuffer, BGR subpixel
+    /// order and inverted colors.
+    pub fn mini_options() -> ModelOptions {
+        let mut options = ModelOptions::with_all((80, 160), (132, 162), mini_offset);
+        options.set_color_order(ColorOrder::Bgr);
+        options.set_invert_colors(ColorInversion::Inverted);
+
+        options
+    }
+}
+
+// ST7735s green tab variant, the panel starts at (2, 1) of the framebuffer
+fn green_tab_offset(options: &ModelOptions) -> (u16, u16) {
+    panel_offset(options, (2, 1))
+}
+
+// ST7735s mini variant, the panel starts at (26, 1) of the framebuffer
+fn mini_offset(options: &ModelOptions) -> (u16, u16) {
+    panel_offset(options, (26, 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Orientation;
+
+    use super::*;
+
+    // the panels of both variants are centered in the framebuffer, mirroring keeps the offset and
+    // only exchanging rows and columns swaps it
+    fn assert_offsets(mut options: ModelOptions, offset: (u16, u16)) {
+        let swapped = (offset.1, offset.0);
+
+        for (orientation, offset) in [
+            (Orientation::Portrait(false), offset),
+            (Orientation::Portrait(true), offset),
+            (Orientation::PortraitInverted(false), offset),
+            (Orientation::PortraitInverted(true), offset),
+            (Orientation::Landscape(false), swapped),
+            (Orientation::Landscape(true), swapped),
+            (Orientation::LandscapeInverted(false), swapped),
+            (Orientation::LandscapeInverted(true), swapped),
+        ] {
+            options.set_orientation(orientation);
+            assert_eq!(options.window_offset(), offset, "{:?}", orientation);
+        }
+    }
+
+    #[test]
+    fn mini_offset_follows_orientation() {
+        assert_offsets(ST7735s::mini_options(), (26, 1));
+    }
+
+    #[test]
+    fn green_tab_offset_follows_orientation() {
+        assert_offsets(ST7735s::green_tab_options(), (2, 1));
+    }
+}
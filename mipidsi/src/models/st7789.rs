@@ -1,18 +1,17 @@
-use display_interface::{DataFormat, WriteOnlyDataCommand, AsyncWriteOnlyDataCommand};
+use display_interface::{DataFormat, WriteOnlyDataCommand};
 use embedded_graphics_core::{pixelcolor::Rgb565, prelude::IntoStorage};
 use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
-use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
 
 use crate::{
     dcs::{
         BitsPerPixel, Dcs, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
-        SetDisplayOn, SetInvertMode, SetPixelFormat, SetScrollArea, SoftReset, WriteMemoryStart, AsyncDcs,
+        SetDisplayOn, SetInvertMode, SetPixelFormat, SetScrollArea, SoftReset, WriteMemoryStart,
     },
     error::InitError,
     ColorInversion, Error, ModelOptions,
 };
 
-use super::{Model, AsyncModel};
+use super::{BrightnessControl, Model};
 
 /// Module containing all ST7789 variants.
 mod variants;
@@ -21,12 +20,6 @@ mod variants;
 ///
 /// Interfaces implemented by the [display-interface](https://crates.io/crates/display-interface) are supported.
 pub struct ST7789;
-/// ST7789 display in Rgb565 color mode.
-/// With framebuffer on the MCU. Data only get's sent to the display with a call to [crate::AsyncDisplay::flush].
-/// Interfaces implemented by the [display-interface](https://crates.io/crates/display-interface) are supported.
-pub struct ST7789Framebuffer<'framebuffer> {
-    framebuffer: &'framebuffer mut [u16; 240 * 135],
-}
 
 impl Model for ST7789 {
     type ColorFormat = Rgb565;
@@ -79,7 +72,14 @@ impl Model for ST7789 {
         I: IntoIterator<Item = Self::ColorFormat>,
     {
         dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
 
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
         let mut iter = colors.into_iter().map(Rgb565::into_storage);
 
         let buf = DataFormat::U16BEIter(&mut iter);
@@ -95,84 +95,4 @@ impl Model for ST7789 {
     }
 }
 
-impl<'framebuffer> AsyncModel for ST7789Framebuffer<'framebuffer> {
-    type ColorFormat = Rgb565;
-
-    async fn init<RST, DELAY, DI>(
-        &mut self,
-        dcs: &mut AsyncDcs<DI>,
-        delay: &mut DELAY,
-        options: &ModelOptions,
-        rst: &mut Option<RST>,
-    ) -> Result<SetAddressMode, InitError<RST::Error>>
-    where
-        RST: OutputPin,
-        DELAY: AsyncDelayNs,
-        DI: AsyncWriteOnlyDataCommand,
-    {
-        let madctl = SetAddressMode::from(options);
-
-        match rst {
-            Some(ref mut rst) => self.hard_reset(rst, delay).await?,
-            None => dcs.write_command(SoftReset).await?,
-        }
-        delay.delay_us(150_000).await;
-
-        dcs.write_command(ExitSleepMode).await?;
-        delay.delay_us(10_000).await;
-
-        // set hw scroll area based on framebuffer size
-        dcs.write_command(SetScrollArea::from(options)).await?;
-        dcs.write_command(madctl).await?;
-
-        dcs.write_command(SetInvertMode(options.invert_colors)).await?;
-
-        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
-        dcs.write_command(SetPixelFormat::new(pf)).await?;
-        delay.delay_us(10_000).await;
-        dcs.write_command(EnterNormalMode).await?;
-        delay.delay_us(10_000).await;
-        dcs.write_command(SetDisplayOn).await?;
-
-        // DISPON requires some time otherwise we risk SPI data issues
-        delay.delay_us(120_000).await;
-
-        Ok(madctl)
-    }
-    
-    fn clear(&mut self, color: Self::ColorFormat) -> Result<(), Error> {
-        *self.framebuffer = [color.into_storage(); 240 * 135];
-        
-        Ok(())
-    }
-
-    fn write_pixel(&mut self, x: u16, y: u16, colors: Self::ColorFormat) -> Result<(), Error> {
-        let Some(framebuffer) = self.framebuffer.get_mut((x + y * 240) as usize) else {
-            defmt::info!("wrong pixel: x = {}; y = {}", x, y);
-            panic!();
-        };
-        *framebuffer = colors.into_storage();
-        //*self.framebuffer.get_mut((x + y * 135) as usize).expect("wrong index") = colors.into_storage();
-        
-        Ok(())
-    }
-
-    fn default_options() -> crate::ModelOptions {
-        let mut options = ModelOptions::with_sizes((240, 320), (240, 320));
-        options.set_invert_colors(ColorInversion::Normal);
-
-        options
-    }
-    
-    async fn flush<DI>(&mut self, dcs: &mut AsyncDcs<DI>) -> Result<(), Error> 
-    where
-        DI: AsyncWriteOnlyDataCommand
-    {
-        dcs.write_command(WriteMemoryStart).await?;
-        
-        dcs.di.send_data(DataFormat::U16BE(self.framebuffer)).await?;
-        
-        Ok(())
-    }
-    
-}
\ No newline at end of file
+impl BrightnessControl for ST7789 {}
@@ -1,8 +1,8 @@
-use display_interface::{WriteOnlyDataCommand, AsyncWriteOnlyDataCommand};
+use display_interface::WriteOnlyDataCommand;
 
-use crate::{Builder, ColorInversion, ModelOptions, Orientation, builder::AsyncBuilder};
+use crate::{options::panel_offset, Builder, ColorInversion, ModelOptions, Orientation};
 
-use super::{ST7789, ST7789Framebuffer};
+use super::ST7789;
 
 impl<DI> Builder<DI, ST7789>
 where
@@ -30,33 +30,122 @@ where
     /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
     ///
     pub fn st7789_pico1(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::pico1_options())
+    }
+
+    /// Creates a new display builder for a 1.3" 240x240 ST7789 display in Rgb565 color mode.
+    ///
+    /// The 240x240 variant uses a display size of 240x240 inside a 240x320 framebuffer and
+    /// inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7789_240x240(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::options_240x240())
+    }
+
+    /// Creates a new display builder for a 1.69" 240x280 ST7789 display in Rgb565 color mode.
+    ///
+    /// The 240x280 variant uses a display size of 240x280 inside a 240x320 framebuffer and
+    /// inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7789_240x280(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::options_240x280())
+    }
+
+    /// Creates a new display builder for a 1.47" 172x320 ST7789 display in Rgb565 color mode.
+    ///
+    /// The 172x320 variant uses a display size of 172x320 inside a 240x320 framebuffer and
+    /// inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7789_172x320(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::options_172x320())
+    }
+
+    /// Creates a new display builder for a 1.9" 170x320 ST7789 display in Rgb565 color mode.
+    ///
+    /// The 170x320 variant uses a display size of 170x320 inside a 240x320 framebuffer and
+    /// inverted colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7789_170x320(di: DI) -> Self {
+        Self::new(di, ST7789, ST7789::options_170x320())
+    }
+}
+
+impl ST7789 {
+    /// Returns the [ModelOptions] of the pico1 variant of a ST7789 display.
+    ///
+    /// The pico1 variant uses a display and framebuffer size of 135x240, inverted colors and a
+    /// clipping offset.
+    pub fn pico1_options() -> ModelOptions {
         let mut options = ModelOptions::with_all((135, 240), (135, 240), pico1_offset);
         options.set_invert_colors(ColorInversion::Inverted);
 
         // pico v1 is cropped to 135x240 size with an offset of (40, 53)
-        Self::new(di, ST7789, options)
+        options
     }
-}
 
-impl<'framebuffer, DI> AsyncBuilder<DI, ST7789Framebuffer<'framebuffer>>
-where
-    DI: AsyncWriteOnlyDataCommand,
-{
-    /// Creates a new display builder for a ST7789 display in Rgb565 color mode with a integrated framebuffer.
-    /// 
-    /// Currently this only works for a 240x135 display.
+    /// Returns the [ModelOptions] of a 1.3" 240x240 ST7789 display.
     ///
-    /// # Arguments
+    /// The 240x240 variant uses a display size of 240x240 inside a 240x320 framebuffer and
+    /// inverted colors.
+    pub fn options_240x240() -> ModelOptions {
+        let mut options = ModelOptions::with_all((240, 240), (240, 320), offset_240x240);
+        options.set_invert_colors(ColorInversion::Inverted);
+
+        options
+    }
+
+    /// Returns the [ModelOptions] of a 1.69" 240x280 ST7789 display.
     ///
-    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
-    /// * `framebuffer` - the framebuffer to store the data. [embedded_graphics_core::draw_target::DrawTarget] operations only write to the framebuffer and a [crate::AsyncModel::flush] call is necessary to actually send the data.
-    pub fn st7789_framebuffer(di: DI, framebuffer: &'framebuffer mut [u16; 240 * 135]) -> Self {
-        Self::with_model(di, ST7789Framebuffer { framebuffer })
+    /// The 240x280 variant uses a display size of 240x280 inside a 240x320 framebuffer and
+    /// inverted colors.
+    pub fn options_240x280() -> ModelOptions {
+        let mut options = ModelOptions::with_all((240, 280), (240, 320), offset_240x280);
+        options.set_invert_colors(ColorInversion::Inverted);
+
+        options
+    }
+
+    /// Returns the [ModelOptions] of a 1.47" 172x320 ST7789 display.
+    ///
+    /// The 172x320 variant uses a display size of 172x320 inside a 240x320 framebuffer and
+    /// inverted colors.
+    pub fn options_172x320() -> ModelOptions {
+        let mut options = ModelOptions::with_all((172, 320), (240, 320), offset_172x320);
+        options.set_invert_colors(ColorInversion::Inverted);
+
+        options
+    }
+
+    /// Returns the [ModelOptions] of a 1.9" 170x320 ST7789 display.
+    ///
+    /// The 170x320 variant uses a display size of 170x320 inside a 240x320 framebuffer and
+    /// inverted colors.
+    pub fn options_170x320() -> ModelOptions {
+        let mut options = ModelOptions::with_all((170, 320), (240, 320), offset_170x320);
+        options.set_invert_colors(ColorInversion::Inverted);
+
+        options
     }
 }
 
 // ST7789 pico1 variant with variable offset
-pub(crate) fn pico1_offset(options: &ModelOptions) -> (u16, u16) {
+fn pico1_offset(options: &ModelOptions) -> (u16, u16) {
     match options.orientation() {
         Orientation::Portrait(false) => (52, 40),
         Orientation::Portrait(true) => (53, 40),
@@ -68,3 +157,68 @@ pub(crate) fn pico1_offset(options: &ModelOptions) -> (u16, u16) {
         Orientation::LandscapeInverted(true) => (40, 52),
     }
 }
+
+// ST7789 240x240 variant, the panel starts at (0, 0) of the framebuffer
+fn offset_240x240(options: &ModelOptions) -> (u16, u16) {
+    panel_offset(options, (0, 0))
+}
+
+// ST7789 240x280 variant, the panel starts at (0, 20) of the framebuffer
+fn offset_240x280(options: &ModelOptions) -> (u16, u16) {
+    panel_offset(options, (0, 20))
+}
+
+// ST7789 172x320 variant, the panel starts at (34, 0) of the framebuffer
+fn offset_172x320(options: &ModelOptions) -> (u16, u16) {
+    panel_offset(options, (34, 0))
+}
+
+// ST7789 170x320 variant, the panel starts at (35, 0) of the framebuffer
+fn offset_170x320(options: &ModelOptions) -> (u16, u16) {
+    panel_offset(options, (35, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_240x240_follows_orientation() {
+        let mut options = ST7789::options_240x240();
+
+        // the panel only leaves space at the bottom of the framebuffer, so every orientation
+        // needs to be checked
+        for (orientation, offset) in [
+            (Orientation::Portrait(false), (0, 0)),
+            (Orientation::Portrait(true), (0, 0)),
+            (Orientation::PortraitInverted(false), (0, 80)),
+            (Orientation::PortraitInverted(true), (0, 80)),
+            (Orientation::Landscape(false), (0, 0)),
+            (Orientation::Landscape(true), (0, 0)),
+            (Orientation::LandscapeInverted(false), (80, 0)),
+            (Orientation::LandscapeInverted(true), (80, 0)),
+        ] {
+            options.set_orientation(orientation);
+            assert_eq!(options.window_offset(), offset, "{:?}", orientation);
+        }
+    }
+
+    #[test]
+    fn offset_saturates_for_display_larger_than_framebuffer() {
+        let mut options = ST7789::options_240x240();
+        options.set_display_size((240, 330));
+
+        options.set_orientation(Orientation::PortraitInverted(false));
+        assert_eq!(options.window_offset(), (0, 0));
+    }
+
+    #[test]
+    fn offset_172x320_follows_orientation() {
+        let mut options = ST7789::options_172x320();
+
+        options.set_orientation(Orientation::Portrait(true));
+        assert_eq!(options.window_offset(), (34, 0));
+        options.set_orientation(Orientation::Landscape(true));
+        assert_eq!(options.window_offset(), (0, 34));
+    }
+}
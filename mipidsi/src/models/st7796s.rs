@@ -0,0 +1,310 @@
+use display_interface::{DataFormat, WriteOnlyDataCommand};
+use embedded_graphics_core::{
+    pixelcolor::{Rgb565, Rgb666},
+    prelude::{IntoStorage, RgbColor},
+};
+use embedded_hal::{blocking::delay::DelayUs, digital::v2::OutputPin};
+
+use crate::{
+    dcs::{
+        BitsPerPixel, Dcs, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
+        SetDisplayOn, SetInvertMode, SetPixelFormat, SoftReset, WriteMemoryStart,
+    },
+    error::InitError,
+    Builder, ColorOrder, Error, ModelOptions,
+};
+
+use super::{BrightnessControl, GammaControl, Model};
+
+/// ST7796S display in Rgb565 color mode.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ST7796SRgb565 {
+    gamma: ST7796SGamma,
+}
+
+/// ST7796S display in Rgb666 color mode.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ST7796SRgb666 {
+    gamma: ST7796SGamma,
+}
+
+/// ST7796S gamma table.
+///
+/// Contains the parameters of the positive (PGC, 0xE0) and negative (NGC, 0xE1) gamma control
+/// registers, see the ST7796S datasheet for the meaning of the individual values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ST7796SGamma {
+    /// Positive gamma control parameters.
+    pub positive: [u8; 14],
+    /// Negative gamma control parameters.
+    pub negative: [u8; 14],
+}
+
+impl ST7796SGamma {
+    /// Gamma table which is used if no other table is set.
+    pub const DEFAULT: Self = Self {
+        positive: [
+            0xF0, 0x09, 0x0B, 0x06, 0x04, 0x15, 0x2F, 0x54, 0x42, 0x3C, 0x17, 0x14, 0x18, 0x1B,
+        ],
+        negative: [
+            0xE0, 0x09, 0x0B, 0x06, 0x04, 0x03, 0x2B, 0x43, 0x42, 0x3B, 0x16, 0x14, 0x17, 0x1B,
+        ],
+    };
+}
+
+impl Default for ST7796SGamma {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Model for ST7796SRgb565 {
+    type ColorFormat = Rgb565;
+
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayUs<u32>,
+        DI: WriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => self.hard_reset(rst, delay)?,
+            None => dcs.write_command(SoftReset)?,
+        }
+        delay.delay_us(120_000);
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common(dcs, delay, options, pf, &self.gamma)?)
+    }
+
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        let mut iter = colors.into_iter().map(|c| c.into_storage());
+
+        let buf = DataFormat::U16BEIter(&mut iter);
+        dcs.di.send_data(buf)
+    }
+
+    fn default_options() -> ModelOptions {
+        default_options()
+    }
+}
+
+impl Model for ST7796SRgb666 {
+    type ColorFormat = Rgb666;
+
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayUs<u32>,
+        DI: WriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => self.hard_reset(rst, delay)?,
+            None => dcs.write_command(SoftReset)?,
+        }
+        delay.delay_us(120_000);
+
+        let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
+        Ok(init_common(dcs, delay, options, pf, &self.gamma)?)
+    }
+
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart)?;
+        self.write_pixel_data(dcs, colors)
+    }
+
+    fn write_pixel_data<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        let mut iter = colors.into_iter().flat_map(|c| {
+            let red = c.r() << 2;
+            let green = c.g() << 2;
+            let blue = c.b() << 2;
+            [red, green, blue]
+        });
+
+        let buf = DataFormat::U8Iter(&mut iter);
+        dcs.di.send_data(buf)
+    }
+
+    fn default_options() -> ModelOptions {
+        default_options()
+    }
+}
+
+impl GammaControl for ST7796SRgb565 {
+    type Gamma = ST7796SGamma;
+
+    fn gamma(&self) -> &Self::Gamma {
+        &self.gamma
+    }
+
+    fn set_gamma(&mut self, gamma: Self::Gamma) {
+        self.gamma = gamma;
+    }
+}
+
+impl GammaControl for ST7796SRgb666 {
+    type Gamma = ST7796SGamma;
+
+    fn gamma(&self) -> &Self::Gamma {
+        &self.gamma
+    }
+
+    fn set_gamma(&mut self, gamma: Self::Gamma) {
+        self.gamma = gamma;
+    }
+}
+
+impl BrightnessControl for ST7796SRgb565 {}
+
+impl BrightnessControl for ST7796SRgb666 {}
+
+// simplified constructor for Display
+
+impl<DI> Builder<DI, ST7796SRgb565>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ST7796S display in Rgb565 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7796s_rgb565(di: DI) -> Self {
+        Self::with_model(di, ST7796SRgb565::default())
+    }
+}
+
+impl<DI> Builder<DI, ST7796SRgb666>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Creates a new display builder for an ST7796S display in Rgb666 color mode.
+    ///
+    /// The default framebuffer size and display size is 320x480 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    ///
+    pub fn st7796s_rgb666(di: DI) -> Self {
+        Self::with_model(di, ST7796SRgb666::default())
+    }
+}
+
+// default options for all color format models
+fn default_options() -> ModelOptions {
+    let mut options = ModelOptions::with_sizes((320, 480), (320, 480));
+    // most ST7796S modules use a BGR panel
+    options.set_color_order(ColorOrder::Bgr);
+
+    options
+}
+
+// common init for all color format models
+fn init_common<DELAY, DI>(
+    dcs: &mut Dcs<DI>,
+    delay: &mut DELAY,
+    options: &ModelOptions,
+    pixel_format: PixelFormat,
+    gamma: &ST7796SGamma,
+) -> Result<SetAddressMode, Error>
+where
+    DELAY: DelayUs<u32>,
+    DI: WriteOnlyDataCommand,
+{
+    let madctl = SetAddressMode::from(options);
+    dcs.write_command(ExitSleepMode)?; // turn off sleep
+    delay.delay_us(120_000);
+
+    dcs.write_raw(0xF0, &[0xC3])?; // enable command set part 1
+    dcs.write_raw(0xF0, &[0x96])?; // enable command set part 2
+
+    dcs.write_command(madctl)?; // set memory data access control
+    dcs.write_command(SetPixelFormat::new(pixel_format))?; // pixel format
+    dcs.write_raw(0xB4, &[0x01])?; // 1-dot inversion control
+    dcs.write_raw(0xB6, &[0x80, 0x02, 0x3B])?; // DFC
+    dcs.write_raw(0xE8, &[0x40, 0x8A, 0x00, 0x00, 0x29, 0x19, 0xA5, 0x33])?; // display output ctrl adjust
+    dcs.write_raw(0xC1, &[0x06])?; // power control 2
+    dcs.write_raw(0xC2, &[0xA7])?; // power control 3
+    dcs.write_raw(0xC5, &[0x18])?; // VCOM control
+    delay.delay_us(120_000);
+
+    dcs.write_raw(0xE0, &gamma.positive)?; // positive gamma control
+    dcs.write_raw(0xE1, &gamma.negative)?; // negative gamma control
+    delay.delay_us(120_000);
+
+    dcs.write_raw(0xF0, &[0x3C])?; // disable command set part 1
+    dcs.write_raw(0xF0, &[0x69])?; // disable command set part 2
+
+    dcs.write_command(SetInvertMode(options.invert_colors))?;
+    dcs.write_command(EnterNormalMode)?; // turn to normal mode
+    dcs.write_command(SetDisplayOn)?; // turn on display
+
+    // DISPON requires some time otherwise we risk SPI data issues
+    delay.delay_us(120_000);
+
+    Ok(madctl)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock::{MockDelay, MockDisplayInterface, MockOutputPin};
+
+    use super::*;
+
+    #[test]
+    fn init_locks_command_set_after_setup() {
+        let display = Builder::st7796s_rgb666(MockDisplayInterface::new())
+            .init(&mut MockDelay, None::<MockOutputPin>)
+            .unwrap();
+
+        let log = display.dcs.di.log();
+        let unlock = log
+            .windows(4)
+            .position(|w| w == [0xF0, 0xC3, 0xF0, 0x96])
+            .unwrap();
+        let lock = log
+            .windows(4)
+            .position(|w| w == [0xF0, 0x3C, 0xF0, 0x69])
+            .unwrap();
+        let gamma = log.windows(2).position(|w| w == [0xE0, 0xF0]).unwrap();
+        assert!(unlock < gamma && gamma < lock, "{:?}", log);
+        assert_eq!(&log[log.len() - 2..], &[0x13, 0x29]);
+    }
+}
@@ -55,22 +55,65 @@ impl ModelOptions {
         }
     }
 
+    /// Returns the color inversion setting.
+    pub fn invert_colors(&self) -> ColorInversion {
+        self.invert_colors
+    }
+
     /// Sets the color inversion setting.
     pub fn set_invert_colors(&mut self, color_inversion: ColorInversion) {
         self.invert_colors = color_inversion;
     }
 
+    /// Returns the subpixel color order.
+    pub fn color_order(&self) -> ColorOrder {
+        self.color_order
+    }
+
+    /// Sets the subpixel color order.
+    pub fn set_color_order(&mut self, color_order: ColorOrder) {
+        self.color_order = color_order;
+    }
+
+    /// Returns the refresh order.
+    pub fn refresh_order(&self) -> RefreshOrder {
+        self.refresh_order
+    }
+
+    /// Sets the refresh order.
+    pub fn set_refresh_order(&mut self, refresh_order: RefreshOrder) {
+        self.refresh_order = refresh_order;
+    }
+
+    /// Sets the display size (w, h) without orientation applied.
+    pub fn set_display_size(&mut self, display_size: (u16, u16)) {
+        self.display_size = display_size;
+    }
+
+    /// Sets the framebuffer size (w, h) without orientation applied.
+    pub fn set_framebuffer_size(&mut self, framebuffer_size: (u16, u16)) {
+        self.framebuffer_size = framebuffer_size;
+    }
+
+    /// Sets the window offset handler.
+    pub fn set_window_offset_handler(
+        &mut self,
+        window_offset_handler: fn(&ModelOptions) -> (u16, u16),
+    ) {
+        self.window_offset_handler = window_offset_handler;
+    }
+
     /// Returns the display size based on current orientation and display options.
     ///
     /// Used by models.
-    pub(crate) fn display_size(&self) -> (u16, u16) {
+    pub fn display_size(&self) -> (u16, u16) {
         Self::orient_size(self.display_size, self.orientation())
     }
 
     /// Returns framebuffer size based on current orientation and display options.
     ///
     /// Used by models. Uses display_size if framebuffer_size is not set.
-    pub(crate) fn framebuffer_size(&self) -> (u16, u16) {
+    pub fn framebuffer_size(&self) -> (u16, u16) {
         let size = if self.framebuffer_size == (0, 0) {
             self.display_size
         } else {
@@ -91,8 +134,8 @@ impl ModelOptions {
 
     /// Returns window offset (x, y) based on current orientation and display options.
     ///
-    /// Used by [Display::set_address_window](crate::Display::set_address_window).
-    pub(crate) fn window_offset(&mut self) -> (u16, u16) {
+    /// Used by displays to translate the address window.
+    pub fn window_offset(&mut self) -> (u16, u16) {
         (self.window_offset_handler)(self)
     }
 
@@ -140,6 +183,36 @@ fn no_offset(options: &ModelOptions) -> (u16, u16) {
     }
 }
 
+// Translates the portrait offset of a panel inside the framebuffer to the current orientation.
+//
+// Mirroring the column (MX) or row (MY) address order moves the panel to the opposite side of
+// the framebuffer, row/column exchange (MV) swaps the x and y offsets. The display size can be
+// changed by the user, a panel which doesn't fit into the framebuffer gets a mirrored offset of 0.
+pub(crate) fn panel_offset(options: &ModelOptions, offset: (u16, u16)) -> (u16, u16) {
+    let (x, y) = offset;
+    let mirrored_x = options
+        .framebuffer_size
+        .0
+        .saturating_sub(options.display_size.0)
+        .saturating_sub(x);
+    let mirrored_y = options
+        .framebuffer_size
+        .1
+        .saturating_sub(options.display_size.1)
+        .saturating_sub(y);
+
+    match options.orientation() {
+        Orientation::Portrait(false) => (x, y),
+        Orientation::Portrait(true) => (mirrored_x, y),
+        Orientation::PortraitInverted(false) => (mirrored_x, mirrored_y),
+        Orientation::PortraitInverted(true) => (x, mirrored_y),
+        Orientation::Landscape(false) => (y, x),
+        Orientation::Landscape(true) => (y, mirrored_x),
+        Orientation::LandscapeInverted(false) => (mirrored_y, mirrored_x),
+        Orientation::LandscapeInverted(true) => (mirrored_y, x),
+    }
+}
+
 ///
 /// Display orientation.
 ///
@@ -278,6 +351,21 @@ pub enum TearingEffect {
     HorizontalAndVertical,
 }
 
+/// Content adaptive brightness control (CABC) mode.
+///
+/// CABC reduces the backlight brightness depending on the displayed image content.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CabcMode {
+    /// Disable CABC.
+    Off,
+    /// Optimized for user interface images.
+    UserInterface,
+    /// Optimized for still pictures.
+    StillPicture,
+    /// Optimized for moving images.
+    MovingImage,
+}
+
 /// Subpixel order.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColorOrder {
@@ -0,0 +1,60 @@
+//! Incremental pixel writes into a display window.
+
+use display_interface::WriteOnlyDataCommand;
+use embedded_hal::digital::v2::OutputPin;
+
+use crate::{dcs, models::Model, Display, Error};
+
+/// Writer for streaming pixels into a rectangular window of the display.
+///
+/// Created by [`Display::window`]. The pixels are written row first, starting in the top left
+/// corner of the window. The first call to [`write_pixels`](Self::write_pixels) starts a new
+/// memory write, every following call continues where the previous call stopped. This allows an
+/// image to be sent in chunks, e.g. while it is read from flash or received over the network.
+///
+/// The writer borrows the display mutably, which makes sure that no other commands interrupt
+/// the memory write.
+pub struct WindowWriter<'a, DI, M, RST>
+where
+    DI: WriteOnlyDataCommand,
+    M: Model,
+    RST: OutputPin,
+{
+    display: &'a mut Display<DI, M, RST>,
+    started: bool,
+}
+
+impl<'a, DI, M, RST> WindowWriter<'a, DI, M, RST>
+where
+    DI: WriteOnlyDataCommand,
+    M: Model,
+    RST: OutputPin,
+{
+    pub(crate) fn new(display: &'a mut Display<DI, M, RST>) -> Self {
+        Self {
+            display,
+            started: false,
+        }
+    }
+
+    ///
+    /// Writes the next chunk of pixels into the window.
+    ///
+    /// Drawing wraps around to the top left corner of the window if more pixels are written
+    /// than the window contains.
+    ///
+    pub fn write_pixels<T>(&mut self, colors: T) -> Result<(), Error>
+    where
+        T: IntoIterator<Item = M::ColorFormat>,
+    {
+        let display = &mut *self.display;
+
+        if self.started {
+            display.dcs.write_command(dcs::WriteMemoryContinue)?;
+            display.model.write_pixel_data(&mut display.dcs, colors)
+        } else {
+            self.started = true;
+            display.model.write_pixels(&mut display.dcs, colors)
+        }
+    }
+}